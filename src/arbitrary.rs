@@ -0,0 +1,158 @@
+//! Property testing strategies for the types of this crate (requires "proptest" feature).
+//!
+//! Randomized players (i.e. [`crate::players::Random`]) are never generated so the generated
+//! values behave deterministically.
+
+use proptest::{collection::vec, prelude::*};
+
+use crate::{
+    errors::ArenaError, genetics::GeneticStrategy, machines::Machine, matches::Arena,
+    matrices::GameMatrix, players::*, traits::PlayerTrait,
+};
+
+/// Get a strategy for a pair of rewards.
+fn rewards() -> impl Strategy<Value = (isize, isize)> {
+    (-10isize..=10, -10isize..=10)
+}
+
+impl Arbitrary for GameMatrix<isize> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (rewards(), rewards(), rewards(), rewards())
+            .prop_map(|(cc, cd, dc, dd)| Self { cc, cd, dc, dd })
+            .boxed()
+    }
+}
+
+impl Arbitrary for BuiltinPlayer {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Self::CopyCat(Default::default())),
+            Just(Self::AllCooperate(AllCooperate)),
+            Just(Self::AllCheat(AllCheat)),
+            Just(Self::Grudger(Default::default())),
+            vec(any::<bool>(), 0..=8).prop_map(|s| Self::Detective(Detective::new(s))),
+            (0usize..=5).prop_map(|m| Self::KindCopyCat(KindCopyCat::new(m))),
+            Just(Self::Simpleton(Default::default())),
+        ]
+        .boxed()
+    }
+}
+
+/// A small configuration of an [`Arena`] on the default machine.
+#[derive(Debug, Clone)]
+pub struct ArenaConfig {
+    /// The player types of the arena.
+    pub player_types: Vec<BuiltinPlayer>,
+    /// The initial population (IDs of `player_types`).
+    pub population: Vec<usize>,
+    /// Rounds per play for each two opponents.
+    pub rounds: usize,
+}
+
+impl ArenaConfig {
+    /// Construct the arena of this configuration.
+    pub fn build(&self, strategy: GeneticStrategy) -> Result<Arena<isize>, ArenaError> {
+        Arena::new(
+            Machine::default(),
+            self.player_types
+                .iter()
+                .map(|p| Box::new(p.clone()) as Box<dyn PlayerTrait<isize>>)
+                .collect(),
+            self.population.clone(),
+            self.rounds,
+            strategy,
+        )
+    }
+}
+
+impl Arbitrary for ArenaConfig {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        vec(any::<BuiltinPlayer>(), 1..=4)
+            .prop_flat_map(|player_types| {
+                let population = vec(0..player_types.len(), 1..=12);
+                (Just(player_types), population, 1usize..=10)
+            })
+            .prop_map(|(player_types, population, rounds)| Self {
+                player_types,
+                population,
+                rounds,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        matches::MatchBuilder,
+        traits::{MachineTrait, MatchTrait},
+    };
+
+    proptest! {
+        #[test]
+        fn test_machine_scores_are_sum_of_plays(
+            matrix in any::<GameMatrix<isize>>(),
+            players in any::<(BuiltinPlayer, BuiltinPlayer)>(),
+            rounds in 0usize..30,
+        ) {
+            let mut game = MatchBuilder::<_, _>::new()
+                .machine(Machine::new(matrix))
+                .players(players.0, players.1)
+                .build();
+
+            let mut expected = (0, 0);
+            for _ in 0..rounds {
+                let consents = (
+                    game.players.0.cooperation_consent(),
+                    game.players.1.cooperation_consent(),
+                );
+                let rewards = game.machine.play_off_record(consents);
+                expected.0 += rewards.0;
+                expected.1 += rewards.1;
+                game.play();
+            }
+            prop_assert_eq!(game.machine.scores(), expected);
+        }
+
+        #[test]
+        fn test_forget_games_is_fresh(
+            player in any::<BuiltinPlayer>(),
+            script in vec(any::<(bool, bool)>(), 0..20),
+        ) {
+            let replay = |player: &mut BuiltinPlayer| {
+                script
+                    .iter()
+                    .map(|&consents| {
+                        let consent = player.cooperation_consent();
+                        player.memorize_last_game(consents, (1, 1));
+                        consent
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let mut fresh = player.clone();
+            let mut used = player;
+            replay(&mut used);
+            used.forget_games();
+            prop_assert_eq!(replay(&mut used), replay(&mut fresh));
+        }
+
+        #[test]
+        fn test_arena_keep_preserves_population(config in any::<ArenaConfig>()) {
+            let mut arena = config.build(GeneticStrategy::Keep).unwrap();
+            for snapshot in arena.run_for_generations(3) {
+                prop_assert_eq!(snapshot.population.len(), config.population.len());
+            }
+        }
+    }
+}
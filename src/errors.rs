@@ -7,6 +7,35 @@ use std::fmt;
 pub enum ArenaError {
     /// Thrown when a player type cannot be known (ID larger than types).
     UnknownPlayer,
+    /// Thrown when there are no players in the initial population.
+    EmptyPlayers,
+    /// Thrown when there are no player types (constructors) to populate from.
+    EmptyConstructors,
+    /// Thrown when the rounds per match is zero.
+    RoundCountZero,
+    /// Thrown when the chance of playing another round is not in `0.0..=1.0`.
+    InvalidContinueProbability,
+    /// Thrown when no machine is given to the builder.
+    MissingMachine,
+    /// Thrown when the cells of a grid do not fill its rows (or the width is zero).
+    InvalidGridShape,
+    /// Thrown when the genetic strategy does not fit the population (see
+    /// [`std::error::Error::source`]).
+    Genetic(GeneticError),
+    /// Thrown when the state of an arena not built from a roster of built-in players is
+    /// exported.
+    MissingRoster,
+    /// Thrown when the state of the arena is not valid JSON of an arena (see
+    /// [`std::error::Error::source`]).
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+}
+
+impl ArenaError {
+    /// Wrap the error of the genetic strategy of the arena.
+    pub fn from_genetic(error: GeneticError) -> Self {
+        Self::Genetic(error)
+    }
 }
 
 impl fmt::Display for ArenaError {
@@ -16,9 +45,229 @@ impl fmt::Display for ArenaError {
             "{}",
             match self {
                 Self::UnknownPlayer => "The given ID in the players list is not in constructors.",
+                Self::EmptyPlayers => "The initial population has no players.",
+                Self::EmptyConstructors => "No player types are given to populate the arena with.",
+                Self::RoundCountZero => "The rounds per match must be more than zero.",
+                Self::InvalidContinueProbability => {
+                    "The chance of playing another round must be in 0.0..=1.0."
+                }
+                Self::MissingMachine => "No machine is given for the arena.",
+                Self::InvalidGridShape => "The cells do not fill the rows of the grid.",
+                Self::Genetic(_) => "The genetic strategy does not fit the population.",
+                Self::MissingRoster => "The arena is not built from a roster of built-in players.",
+                #[cfg(feature = "serde")]
+                Self::Json(_) => "The state is not valid JSON of an arena.",
+            }
+        )
+    }
+}
+
+impl std::error::Error for ArenaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Genetic(source) => Some(source),
+            #[cfg(feature = "serde")]
+            Self::Json(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<GeneticError> for ArenaError {
+    fn from(value: GeneticError) -> Self {
+        Self::from_genetic(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for ArenaError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+/// Indicates a failure in constructing a [`crate::matches::Match`] (see
+/// [`crate::matches::Match::played`]).
+#[derive(Debug)]
+pub enum MatchError {
+    /// Thrown when the rounds to play is zero.
+    ZeroRounds,
+    /// Thrown when both players of the match are the same player (at the same address).
+    IdenticalPlayers,
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::ZeroRounds => "The rounds of the match must be more than zero.",
+                Self::IdenticalPlayers => "Both players of the match are the same player.",
+            }
+        )
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+/// Indicates a failure in running an [`crate::matches::Arena`] until convergence.
+#[derive(Debug)]
+pub enum ConvergenceError {
+    /// Thrown when the maximum number of generations is played without convergence.
+    MaxGenerationsReached,
+}
+
+impl fmt::Display for ConvergenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::MaxGenerationsReached =>
+                    "The population did not converge in the given maximum generations.",
             }
         )
     }
 }
 
-impl std::error::Error for ArenaError {}
+impl std::error::Error for ConvergenceError {}
+
+/// Indicates invalid parameters of a [`crate::genetics::GeneticStrategy`] for a population.
+#[derive(Debug)]
+pub enum GeneticError {
+    /// Thrown when more players are removed than added in each generation.
+    PopulationWouldShrink {
+        /// The players removed in each generation.
+        remove: usize,
+        /// The players added in each generation.
+        add: usize,
+    },
+    /// Thrown when the next generation would have no players.
+    ZeroTargetPopulation,
+    /// Thrown when a tournament selection samples no players or more players than the
+    /// population has.
+    InvalidTournamentSize,
+}
+
+impl fmt::Display for GeneticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PopulationWouldShrink { remove, add } => write!(
+                f,
+                "The population would shrink by removing {remove} and adding {add} players."
+            ),
+            Self::ZeroTargetPopulation => write!(f, "The next generation would have no players."),
+            Self::InvalidTournamentSize => {
+                write!(f, "The tournament size does not fit the population.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeneticError {}
+
+/// Indicates a failure in constructing or using a [`crate::matrices::GameMatrix`].
+#[derive(Debug)]
+pub enum MatrixError {
+    /// Thrown when the parameters do not satisfy the inequality of a Prisoner's Dilemma.
+    PdInequalityViolated {
+        /// The inequality to satisfy (e.g. `t > r > p > s`).
+        expected: String,
+        /// The given parameters.
+        got: String,
+    },
+    /// Thrown when a given probability is not in `0.0..=1.0`.
+    InvalidProbability,
+    /// Thrown when the rewards do not have exactly a row and a column for each move.
+    InvalidDimensions,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PdInequalityViolated { expected, got } => write!(
+                f,
+                "The given parameters ({got}) do not satisfy {expected} of a Prisoner's Dilemma."
+            ),
+            Self::InvalidProbability => {
+                write!(f, "The given probability is not in range 0.0..=1.0.")
+            }
+            Self::InvalidDimensions => {
+                write!(f, "The rewards do not have a row and a column per move.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+/// Indicates a failure in constructing a player in [`crate::players`] or in playing a
+/// [`crate::traits::TryPlayerTrait`].
+#[derive(Debug)]
+pub enum PlayerError {
+    /// Thrown when a given probability is not in `0.0..=1.0`.
+    InvalidProbability,
+    /// Thrown when a player fails to play for the given reason (see [`std::error::Error::source`]).
+    Failed(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for PlayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InvalidProbability => "The given probability is not in range 0.0..=1.0.",
+                Self::Failed(_) => "The player failed to play.",
+            }
+        )
+    }
+}
+
+impl std::error::Error for PlayerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidProbability => None,
+            Self::Failed(source) => Some(source.as_ref()),
+        }
+    }
+}
+
+/// Indicates a failure in (de)serializing a roster of players (see
+/// [`crate::players::serialize_roster`]).
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum RosterError {
+    /// Thrown when the type of a player is not known to the registry of built-in players.
+    UnknownType(String),
+    /// Thrown when the JSON is malformed or the state does not match its type.
+    Json(serde_json::Error),
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for RosterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownType(name) => write!(f, "The player type \"{name}\" is not known."),
+            Self::Json(_) => write!(f, "The roster is not valid JSON of players."),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for RosterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnknownType(_) => None,
+            Self::Json(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for RosterError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
@@ -1,6 +1,9 @@
 //! Helpers regarding genetic manipulation and evolution algorithms.
 
+use crate::{errors::GeneticError, traits::Nameable};
+
 /// Strategies regarding moving from one generation to another in genetic settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GeneticStrategy {
     /// Keep the population as given (no change).
     Keep,
@@ -9,14 +12,32 @@ pub enum GeneticStrategy {
 }
 
 impl GeneticStrategy {
+    /// Check whether the strategy can be applied to a population of the given size.
+    ///
+    /// Returns Err if the next generation would be empty or smaller than the current one.
+    pub fn validate(&self, current_population: usize) -> Result<(), GeneticError> {
+        match *self {
+            Self::Keep if current_population == 0 => Err(GeneticError::ZeroTargetPopulation),
+            Self::Keep => Ok(()),
+            Self::CullingElitism(remove, add) => {
+                if current_population.saturating_sub(remove) + add == 0 {
+                    Err(GeneticError::ZeroTargetPopulation)
+                } else if remove > add {
+                    Err(GeneticError::PopulationWouldShrink { remove, add })
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
     /// Apply a strategy on the scores aquired by each type (usize is the ID/type of group).
     ///
     /// This only works if the list is already sorted from the best type to the worst in score.
-    /// Note that the results may be unsorted.
-    pub fn apply_to_vec(&self, mut sorted_types: Vec<usize>) -> Vec<usize> {
-        if sorted_types.is_empty() {
-            return vec![];
-        }
+    /// Note that the results may be unsorted. Returns Err if the strategy does not fit the
+    /// population (see [`Self::validate`]).
+    pub fn apply_to_vec(&self, mut sorted_types: Vec<usize>) -> Result<Vec<usize>, GeneticError> {
+        self.validate(sorted_types.len())?;
 
         match self {
             Self::Keep => {}
@@ -30,9 +51,7 @@ impl GeneticStrategy {
                     *to_remove
                 };
 
-                for i in 0..to_remove {
-                    sorted_types.swap_remove(i);
-                }
+                sorted_types.drain(..to_remove);
 
                 for _ in 0..*to_add {
                     sorted_types.push(best);
@@ -40,6 +59,131 @@ impl GeneticStrategy {
             }
         }
 
-        sorted_types
+        Ok(sorted_types)
+    }
+}
+
+/// The serialized form of [`GeneticStrategy`] (tagged by the type, with named parameters).
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename = "GeneticStrategy")]
+enum GeneticStrategyRepr {
+    Keep,
+    CullingElitism { to_remove: usize, to_add: usize },
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GeneticStrategy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Self::Keep => GeneticStrategyRepr::Keep,
+            Self::CullingElitism(to_remove, to_add) => {
+                GeneticStrategyRepr::CullingElitism { to_remove, to_add }
+            }
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GeneticStrategy {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match GeneticStrategyRepr::deserialize(deserializer)? {
+            GeneticStrategyRepr::Keep => Self::Keep,
+            GeneticStrategyRepr::CullingElitism { to_remove, to_add } => {
+                Self::CullingElitism(to_remove, to_add)
+            }
+        })
+    }
+}
+
+impl Nameable for GeneticStrategy {
+    fn label(&self) -> &str {
+        match self {
+            Self::Keep => "Keep",
+            Self::CullingElitism(..) => "CullingElitism",
+        }
+    }
+
+    fn description(&self) -> &str {
+        match self {
+            Self::Keep => "Keep the population as given (no change).",
+            Self::CullingElitism(..) => "Remove the worst and multiply the best.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(matches!(
+            GeneticStrategy::CullingElitism(10, 5).validate(25),
+            Err(GeneticError::PopulationWouldShrink { remove: 10, add: 5 })
+        ));
+        assert!(matches!(
+            GeneticStrategy::CullingElitism(10, 5).apply_to_vec(vec![0; 25]),
+            Err(GeneticError::PopulationWouldShrink { remove: 10, add: 5 })
+        ));
+        assert!(matches!(
+            GeneticStrategy::CullingElitism(5, 0).validate(5),
+            Err(GeneticError::ZeroTargetPopulation)
+        ));
+        assert!(matches!(
+            GeneticStrategy::Keep.validate(0),
+            Err(GeneticError::ZeroTargetPopulation)
+        ));
+        assert!(GeneticStrategy::CullingElitism(5, 5).validate(25).is_ok());
+        assert_eq!(
+            GeneticStrategy::CullingElitism(1, 2)
+                .apply_to_vec(vec![0, 1])
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_apply_removes_more_than_half() {
+        let strategy = GeneticStrategy::CullingElitism(5, 5);
+        assert!(strategy.validate(6).is_ok());
+        assert_eq!(
+            strategy.apply_to_vec(vec![0, 1, 2, 3, 4, 5]).unwrap(),
+            vec![5; 6]
+        );
+        assert_eq!(
+            GeneticStrategy::CullingElitism(2, 3)
+                .apply_to_vec(vec![0, 1, 2])
+                .unwrap(),
+            vec![2; 4]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_strategy() {
+        let json = r#"{"type":"CullingElitism","to_remove":5,"to_add":5}"#;
+        assert_eq!(
+            serde_json::to_string(&GeneticStrategy::CullingElitism(5, 5)).unwrap(),
+            json
+        );
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            GeneticStrategy::CullingElitism(5, 5)
+        ));
+
+        let json = r#"{"type":"Keep"}"#;
+        assert_eq!(serde_json::to_string(&GeneticStrategy::Keep).unwrap(), json);
+        assert!(matches!(
+            serde_json::from_str(json).unwrap(),
+            GeneticStrategy::Keep
+        ));
+
+        let error = serde_json::from_str::<GeneticStrategy>(r#"{"type":"Tournament"}"#)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("unknown variant `Tournament`"));
     }
 }
@@ -0,0 +1,134 @@
+//! Exporting the results of the simulations as CSV for external analysis (requires "io"
+//! feature).
+
+use std::{fmt, io, str::FromStr};
+
+use crate::matches::GenerationSnapshot;
+
+/// The header of the generation history CSV.
+const HEADER: [&str; 4] = ["generation", "player_type", "score", "next_player_type"];
+
+/// Write the snapshots as CSV with one row per generation and player slot.
+///
+/// The columns are `generation`, `player_type` and `score` (of the slot in the generation) and
+/// `next_player_type` (the type in the slot after the generation). A column is empty if the
+/// played and the next population are of different lengths (the population grew or shrank).
+pub fn write_generation_history<T, W>(
+    history: &[GenerationSnapshot<T>],
+    writer: W,
+) -> Result<(), csv::Error>
+where
+    T: fmt::Display,
+    W: io::Write,
+{
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(HEADER)?;
+    for snapshot in history {
+        let slots = snapshot
+            .players
+            .len()
+            .max(snapshot.scores.len())
+            .max(snapshot.population.len());
+        for slot in 0..slots {
+            let cell = |value: Option<String>| value.unwrap_or_default();
+            writer.write_record([
+                snapshot.generation_index.to_string(),
+                cell(snapshot.players.get(slot).map(ToString::to_string)),
+                cell(snapshot.scores.get(slot).map(ToString::to_string)),
+                cell(snapshot.population.get(slot).map(ToString::to_string)),
+            ])?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read the snapshots written by [`write_generation_history`].
+///
+/// The consecutive rows of the same generation make a snapshot. Returns Err if the CSV is
+/// malformed or a value can not be parsed.
+pub fn read_generation_history<T, R>(reader: R) -> Result<Vec<GenerationSnapshot<T>>, csv::Error>
+where
+    T: FromStr,
+    R: io::Read,
+{
+    let mut reader = csv::Reader::from_reader(reader);
+    let mut history: Vec<GenerationSnapshot<T>> = vec![];
+    for record in reader.records() {
+        let record = record?;
+        let field = |i: usize| record.get(i).unwrap_or_default();
+        let generation_index = parse::<usize>(field(0), HEADER[0])?;
+        if history.last().map(|s| s.generation_index) != Some(generation_index) {
+            history.push(GenerationSnapshot {
+                generation_index,
+                players: vec![],
+                population: vec![],
+                scores: vec![],
+            });
+        }
+        let snapshot = history.last_mut().expect("a snapshot is pushed above");
+        if !field(1).is_empty() {
+            snapshot.players.push(parse(field(1), HEADER[1])?);
+        }
+        if !field(2).is_empty() {
+            snapshot.scores.push(parse(field(2), HEADER[2])?);
+        }
+        if !field(3).is_empty() {
+            snapshot.population.push(parse(field(3), HEADER[3])?);
+        }
+    }
+    Ok(history)
+}
+
+/// Parse the value of the given column or get an invalid data error.
+fn parse<T: FromStr>(value: &str, column: &str) -> Result<T, csv::Error> {
+    value.parse().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {} value {:?}", column, value),
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        genetics::GeneticStrategy,
+        machines::Machine,
+        matches::Arena,
+        players::{AllCheat, CopyCat},
+    };
+
+    #[test]
+    fn test_generation_history_round_trip() {
+        let mut arena = <Arena>::builder()
+            .machine(Machine::default())
+            .add_type(Box::new(CopyCat::default()), 4)
+            .add_type(Box::new(AllCheat), 2)
+            .rounds_per_match(10)
+            .strategy(GeneticStrategy::CullingElitism(1, 2))
+            .build()
+            .unwrap();
+        let history = arena.run_for_generations(3);
+
+        let mut buffer = vec![];
+        write_generation_history(&history, &mut buffer).unwrap();
+        let csv = String::from_utf8(buffer).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("generation,player_type,score,next_player_type")
+        );
+        // the population grows by one in each generation so the last slot was not played
+        assert_eq!(csv.lines().count(), 1 + 7 + 8 + 9);
+        assert!(lines.nth(6).unwrap().starts_with("0,,,"));
+
+        let read: Vec<GenerationSnapshot<isize>> = read_generation_history(csv.as_bytes()).unwrap();
+        assert_eq!(read, history);
+
+        let malformed = "generation,player_type,score,next_player_type\n0,copycat,3,0\n";
+        assert!(read_generation_history::<isize, _>(malformed.as_bytes()).is_err());
+    }
+}
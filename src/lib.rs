@@ -6,24 +6,55 @@
 //! - The game matrices (the machine scores) can be asymmetrical.
 //! - Different sandbox generation transfer algorithms (how winners should multiply).
 //!
-//! This crate has an optional "rand" feature which adds [`machines::MachineRandomizer`] and
-//! [`players::Random`] which is disabled by default.
+//! This crate has an optional "rand" feature which adds [`machines::MachineRandomizer`],
+//! `machines::NoisyMachine` (see `matches::Arena::with_noise`) and the randomized players
+//! (i.e. [`players::Random`]) which is disabled by default.
+//! The optional "serde" feature adds serialization to the built-in players (i.e.
+//! [`players::BuiltinPlayer`] and the rosters of `players::serialize_roster`) and to
+//! [`matrices::GameMatrix`], [`machines::Machine`] and [`genetics::GeneticStrategy`] as well
+//! as the state of the arenas built from a roster (see `matches::Arena::export_state`).
+//! The optional "parallel" feature adds `Arena::play_parallel` which plays the matches of each
+//! [`matches::Arena`] generation in parallel (for the [`matches::SendArena`] with cloneable
+//! machines).
+//! The scores are still summed in the order of the pairings so the results are identical to
+//! the sequential play.
+//! The optional "proptest" feature adds the `arbitrary` module for property testing.
+//! The optional "io" feature adds the `io` module to export the generation history as CSV.
 //!
 //! To simulate a community, one needs a match ([`mod@matches`] or equal, ideally implementing
 //! [`traits::MatchTrait`]), which is populated by players ([`players`] or equal, ideally
 //! implementing [`traits::PlayerTrait`]).
+//! Games with more moves than cooperating and cheating are played with [`moves`] instead.
+//! The outcomes can be summarized with [`statistics`] and drawn as text with [`visualize`].
+// The tests predate these lints and are kept as written.
+#![cfg_attr(
+    test,
+    allow(
+        clippy::bool_assert_comparison,
+        clippy::default_constructed_unit_structs,
+        clippy::too_many_arguments
+    )
+)]
 
 pub(crate) mod worm_bools;
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "io")]
+pub mod io;
+
 pub mod errors;
 pub mod genetics;
 pub mod machines;
 pub mod matches;
 pub mod matrices;
+pub mod moves;
 pub mod players;
+pub mod statistics;
 pub mod traits;
+pub mod visualize;
 
 /// Auto include traits.
 pub mod prelude {
-    pub use crate::traits::*;
+    pub use crate::{errors::MatrixError, statistics::*, traits::*};
 }
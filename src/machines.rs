@@ -6,20 +6,41 @@
 //! machine instead of what they put in the machine. In other words, a player must take action on
 //! their registered state, not the state they assume they are in.
 
-use std::ops::AddAssign;
+use std::{fmt, ops::AddAssign};
 
-use crate::{matrices::GameMatrix, traits::MachineTrait};
+use crate::{
+    matrices::GameMatrix,
+    traits::{ExpectedValueMachineTrait, MachineTrait, Nameable, Resettable},
+};
 
 /// The main "engine" of the game which handles payoffs and costs.
 ///
 /// This is a deterministic machine which works always according to the given matrix. This is the
 /// default for most of the logic.
 #[derive(Debug, Clone)]
-pub struct Machine<T> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Machine<T = isize> {
     /// The game matrix regarding this machine.
     pub matrix: GameMatrix<T>,
     /// What are the current scores of this machine being played this much.
     pub scores: (T, T),
+    /// How many rounds are recorded in the scores.
+    pub round_count: usize,
+    /// The consents and the rewards registered in the last round played (see
+    /// [`MachineTrait::last_round`]).
+    pub last_round: Option<((bool, bool), (T, T))>,
+}
+
+impl<T> Nameable for Machine<T> {
+    fn label(&self) -> &str {
+        "Machine"
+    }
+}
+
+impl<T: Clone + Default + AddAssign<T>> Resettable for Machine<T> {
+    fn reset(&mut self) {
+        self.reset_scores()
+    }
 }
 
 impl<T: Default> Machine<T> {
@@ -27,15 +48,35 @@ impl<T: Default> Machine<T> {
         Self {
             matrix,
             scores: Default::default(),
+            round_count: 0,
+            last_round: None,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T> Machine<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Get the state of the machine (the matrix, the scores and the last round) as JSON.
+    pub fn save_snapshot(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Restore a machine from its snapshot (see [`Self::save_snapshot`]).
+    pub fn load_snapshot(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+}
+
 impl Default for Machine<isize> {
     fn default() -> Self {
         Self {
             matrix: Default::default(),
             scores: Default::default(),
+            round_count: 0,
+            last_round: None,
         }
     }
 }
@@ -50,35 +91,243 @@ impl<T: Clone + Default + AddAssign<T>> MachineTrait<T> for Machine<T> {
     }
 
     fn reset_scores(&mut self) {
-        self.scores = Default::default()
+        self.scores = Default::default();
+        self.round_count = 0;
+        self.last_round = None;
     }
 
     fn record_scores(&mut self, last_rewards: (T, T)) {
         self.scores.0 += last_rewards.0;
         self.scores.1 += last_rewards.1;
+        self.round_count += 1;
+    }
+
+    fn round_count(&self) -> usize {
+        self.round_count
+    }
+
+    fn last_round(&self) -> Option<((bool, bool), (T, T))> {
+        self.last_round.clone()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn play(&mut self, consents: (bool, bool)) -> (T, T) {
+        let last_rewards = self.play_off_record(consents);
+        self.record_scores(last_rewards.clone());
+        self.last_round = Some((consents, last_rewards.clone()));
+        last_rewards
+    }
+}
+
+/// A deterministic machine recording the expected rewards of the chances of cooperation.
+///
+/// Playing the consents works like [`Machine`] but playing the chances (see
+/// [`ExpectedValueMachineTrait`]) records the average rewards over the four outcomes instead of
+/// sampling one, which is useful for exact analysis without random noise.
+#[derive(Debug, Clone)]
+pub struct ExpectedValueMachine<T> {
+    /// The game matrix regarding this machine.
+    pub matrix: GameMatrix<T>,
+    /// What are the current (expected) scores of this machine being played this much.
+    pub scores: (f64, f64),
+    /// How many rounds are recorded in the scores.
+    pub round_count: usize,
+}
+
+impl<T> Nameable for ExpectedValueMachine<T> {
+    fn label(&self) -> &str {
+        "ExpectedValueMachine"
+    }
+}
+
+impl<T: Clone + Into<f64>> Resettable for ExpectedValueMachine<T> {
+    fn reset(&mut self) {
+        self.reset_scores()
+    }
+}
+
+impl<T> ExpectedValueMachine<T> {
+    pub fn new(matrix: GameMatrix<T>) -> Self {
+        Self {
+            matrix,
+            scores: Default::default(),
+            round_count: 0,
+        }
+    }
+}
+
+impl<T: Clone + Into<f64>> MachineTrait<f64> for ExpectedValueMachine<T> {
+    fn play_off_record(&self, consents: (bool, bool)) -> (f64, f64) {
+        let rewards = self.matrix.get_for_consents(consents).clone();
+        (rewards.0.into(), rewards.1.into())
+    }
+
+    fn scores(&self) -> (f64, f64) {
+        self.scores
+    }
+
+    fn reset_scores(&mut self) {
+        self.scores = Default::default();
+        self.round_count = 0;
+    }
+
+    fn record_scores(&mut self, last_rewards: (f64, f64)) {
+        self.scores.0 += last_rewards.0;
+        self.scores.1 += last_rewards.1;
+        self.round_count += 1;
+    }
+
+    fn round_count(&self) -> usize {
+        self.round_count
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Clone + Into<f64>> ExpectedValueMachineTrait<f64> for ExpectedValueMachine<T> {
+    /// Panics if any of the probabilities is not in `0.0..=1.0`.
+    fn expect_off_record(&self, probabilities: (f64, f64)) -> (f64, f64) {
+        self.matrix
+            .expected_payoff(probabilities.0, probabilities.1)
+            .expect("chances of cooperation must be in 0.0..=1.0")
+    }
+}
+
+/// Calculates the rewards of the players from their consents (in order).
+pub type GroupPayoff<T> = Box<dyn Fn(&[bool]) -> Vec<T>>;
+
+/// A machine for games of any number of players with a custom payoff function.
+///
+/// The payoff function gets the consents of all the players and returns their rewards in order.
+pub struct GroupMachine<T> {
+    /// Calculates the rewards of the players from their consents.
+    payoff: GroupPayoff<T>,
+    /// What are the current scores of this machine being played this much (one per player).
+    pub scores: Vec<T>,
+}
+
+impl<T> Nameable for GroupMachine<T> {
+    fn label(&self) -> &str {
+        "GroupMachine"
+    }
+}
+
+impl<T> Resettable for GroupMachine<T> {
+    fn reset(&mut self) {
+        self.reset_scores()
+    }
+}
+
+impl<T> GroupMachine<T> {
+    pub fn new(payoff: impl Fn(&[bool]) -> Vec<T> + 'static) -> Self {
+        Self {
+            payoff: Box::new(payoff),
+            scores: vec![],
+        }
+    }
+
+    /// Play a game and return the results (do not record the results anywhere).
+    pub fn play_off_record(&self, consents: &[bool]) -> Vec<T> {
+        (self.payoff)(consents)
+    }
+
+    /// Reset the machine stats.
+    pub fn reset_scores(&mut self) {
+        self.scores.clear();
+    }
+}
+
+impl<T: Clone + Default + AddAssign<T>> GroupMachine<T> {
+    /// Play the inputs and get the outputs (mutating scoreboard and recording each result).
+    pub fn play(&mut self, consents: &[bool]) -> Vec<T> {
+        let last_rewards = self.play_off_record(consents);
+        if self.scores.len() < last_rewards.len() {
+            self.scores.resize(last_rewards.len(), T::default());
+        }
+        for (score, reward) in self.scores.iter_mut().zip(last_rewards.iter()) {
+            *score += reward.clone();
+        }
+        last_rewards
+    }
+}
+
+impl GroupMachine<f64> {
+    /// A public goods game in which each cooperator pays the cost to the pot.
+    ///
+    /// The pot is multiplied and split evenly between all the players.
+    pub fn public_goods(cost: f64, multiplier: f64) -> Self {
+        Self::new(move |consents| {
+            let cooperators = consents.iter().filter(|&&c| c).count();
+            let share = cooperators as f64 * cost * multiplier / consents.len() as f64;
+            consents
+                .iter()
+                .map(|&c| if c { share - cost } else { share })
+                .collect()
+        })
+    }
+}
+
+impl Default for GroupMachine<f64> {
+    fn default() -> Self {
+        Self::public_goods(1.0, 3.0)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GroupMachine<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupMachine")
+            .field("scores", &self.scores)
+            .finish_non_exhaustive()
     }
 }
 
 /// A machine with chances of failure or swapping outputs (requires feature "rand").
 #[cfg(any(feature = "rand", doc))]
-pub struct MachineRandomizer<T> {
+#[derive(Debug, Clone)]
+pub struct MachineRandomizer<T = isize> {
     pub base: Machine<T>,
     /// What are the chances that the player will convert their positive consent to false (`0..=1`).
     pub consent_falsify_chance: (f32, f32),
     /// What are the chances that the player will convert their negative consent to true (`0..=1`).
     pub random_consenter: (f32, f32),
+    /// A seeded generator to use instead of the thread one (see [`MachineTrait::reseed`]).
+    #[cfg(feature = "rand")]
+    pub rng: Option<rand::rngs::StdRng>,
 }
 
 #[cfg(any(feature = "rand", doc))]
-impl<T: Clone + Default + AddAssign<T>> MachineTrait<T> for MachineRandomizer<T> {
-    fn play_off_record(&self, mut consents: (bool, bool)) -> (T, T) {
-        // mutate the contests randomly.
-        let mut rng = rand::thread_rng();
-        let chances = (
-            <rand::rngs::ThreadRng as rand::Rng>::gen::<f32>(&mut rng),
-            <rand::rngs::ThreadRng as rand::Rng>::gen::<f32>(&mut rng),
-        );
+impl<T> Nameable for MachineRandomizer<T> {
+    fn label(&self) -> &str {
+        "MachineRandomizer"
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T: Clone + Default + AddAssign<T>> Resettable for MachineRandomizer<T> {
+    fn reset(&mut self) {
+        self.reset_scores()
+    }
+}
 
+#[cfg(any(feature = "rand", doc))]
+impl<T> MachineRandomizer<T> {
+    /// Roll the chances from the given seed for reproducible games (see
+    /// [`MachineTrait::reseed`]).
+    #[cfg(feature = "rand")]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Some(<rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(
+            seed,
+        ));
+        self
+    }
+
+    /// Mutate the consents given the random chances rolled for each (`0..1`).
+    fn randomize_consents(&self, mut consents: (bool, bool), chances: (f32, f32)) -> (bool, bool) {
         if consents.0 {
             consents.0 = chances.0 > self.consent_falsify_chance.0;
         } else {
@@ -91,7 +340,153 @@ impl<T: Clone + Default + AddAssign<T>> MachineTrait<T> for MachineRandomizer<T>
             consents.1 = chances.1 <= self.random_consenter.1;
         }
 
-        self.base.play_off_record(consents)
+        consents
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T: Clone + Default + AddAssign<T>> MachineTrait<T> for MachineRandomizer<T> {
+    /// Same as [`Machine::play_off_record`] but with mutated consents.
+    ///
+    /// A seeded machine rolls from a copy of its generator so the chances are the same as the
+    /// next round on record.
+    fn play_off_record(&self, consents: (bool, bool)) -> (T, T) {
+        // mutate the contests randomly.
+        let chances = match self.rng.clone() {
+            Some(mut rng) => <rand::rngs::StdRng as rand::Rng>::gen::<(f32, f32)>(&mut rng),
+            None => rand::random::<(f32, f32)>(),
+        };
+
+        self.base
+            .play_off_record(self.randomize_consents(consents, chances))
+    }
+
+    fn play(&mut self, consents: (bool, bool)) -> (T, T) {
+        self.play_registered(consents).1
+    }
+
+    fn play_registered(&mut self, consents: (bool, bool)) -> ((bool, bool), (T, T)) {
+        let chances = match self.rng.as_mut() {
+            Some(rng) => (
+                <rand::rngs::StdRng as rand::Rng>::gen::<f32>(rng),
+                <rand::rngs::StdRng as rand::Rng>::gen::<f32>(rng),
+            ),
+            None => {
+                let mut rng = rand::thread_rng();
+                (
+                    <rand::rngs::ThreadRng as rand::Rng>::gen::<f32>(&mut rng),
+                    <rand::rngs::ThreadRng as rand::Rng>::gen::<f32>(&mut rng),
+                )
+            }
+        };
+        let registered_consents = self.randomize_consents(consents, chances);
+        let last_rewards = self.base.play(registered_consents);
+        (registered_consents, last_rewards)
+    }
+
+    fn scores(&self) -> (T, T) {
+        self.base.scores()
+    }
+
+    fn reset_scores(&mut self) {
+        self.base.reset_scores()
+    }
+
+    fn round_count(&self) -> usize {
+        self.base.round_count()
+    }
+
+    fn last_round(&self) -> Option<((bool, bool), (T, T))> {
+        self.base.last_round()
+    }
+
+    fn record_scores(&mut self, last_rewards: (T, T)) {
+        self.base.record_scores(last_rewards)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = Some(<rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(
+            seed,
+        ));
+    }
+}
+
+/// A layer over any machine which flips each consent by a chance (requires feature "rand").
+///
+/// Unlike [`MachineRandomizer`], the chance is the same for cooperations and defections and the
+/// base machine can be of any type (e.g. a mutable reference to another machine).
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone)]
+pub struct NoisyMachine<M> {
+    pub base: M,
+    /// What are the chances that each consent is flipped (`0..=1`).
+    pub chance: f32,
+    /// A seeded generator to use instead of the thread one (see [`MachineTrait::reseed`]).
+    pub rng: Option<rand::rngs::StdRng>,
+}
+
+#[cfg(feature = "rand")]
+impl<M> NoisyMachine<M> {
+    /// Wrap the machine to flip each consent by the given chance.
+    pub fn new(base: M, chance: f32) -> Self {
+        Self {
+            base,
+            chance,
+            rng: None,
+        }
+    }
+
+    /// Roll the flips from the given seed for reproducible games (the base machine is not
+    /// reseeded).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Some(<rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(
+            seed,
+        ));
+        self
+    }
+
+    /// Flip the consents whose rolled chance (`0..1`) is below the chance of the machine.
+    fn flip_consents(&self, consents: (bool, bool), chances: (f32, f32)) -> (bool, bool) {
+        (
+            consents.0 != (chances.0 < self.chance),
+            consents.1 != (chances.1 < self.chance),
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<M> Nameable for NoisyMachine<M> {
+    fn label(&self) -> &str {
+        "NoisyMachine"
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Clone, M: MachineTrait<T>> MachineTrait<T> for NoisyMachine<M> {
+    /// Same as [`MachineTrait::play_off_record`] of the base machine but with flipped consents.
+    ///
+    /// A seeded machine rolls from a copy of its generator so the flips are the same as the next
+    /// round on record.
+    fn play_off_record(&self, consents: (bool, bool)) -> (T, T) {
+        let chances = match self.rng.clone() {
+            Some(mut rng) => <rand::rngs::StdRng as rand::Rng>::gen::<(f32, f32)>(&mut rng),
+            None => rand::random::<(f32, f32)>(),
+        };
+        self.base
+            .play_off_record(self.flip_consents(consents, chances))
+    }
+
+    fn play(&mut self, consents: (bool, bool)) -> (T, T) {
+        self.play_registered(consents).1
+    }
+
+    fn play_registered(&mut self, consents: (bool, bool)) -> ((bool, bool), (T, T)) {
+        let chances = match self.rng.as_mut() {
+            Some(rng) => <rand::rngs::StdRng as rand::Rng>::gen::<(f32, f32)>(rng),
+            None => rand::random::<(f32, f32)>(),
+        };
+        self.base
+            .play_registered(self.flip_consents(consents, chances))
     }
 
     fn scores(&self) -> (T, T) {
@@ -102,7 +497,23 @@ impl<T: Clone + Default + AddAssign<T>> MachineTrait<T> for MachineRandomizer<T>
         self.base.reset_scores()
     }
 
+    fn round_count(&self) -> usize {
+        self.base.round_count()
+    }
+
+    fn last_round(&self) -> Option<((bool, bool), (T, T))> {
+        self.base.last_round()
+    }
+
     fn record_scores(&mut self, last_rewards: (T, T)) {
         self.base.record_scores(last_rewards)
     }
+
+    /// Reseed the flips of this layer and the base machine (with a derived seed).
+    fn reseed(&mut self, seed: u64) {
+        self.rng = Some(<rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(
+            seed,
+        ));
+        self.base.reseed(seed.wrapping_add(1));
+    }
 }
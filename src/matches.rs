@@ -1,17 +1,31 @@
 //! Different configurations for putting players against each other (1V1 and manyVmany).
 
-use std::{marker::PhantomData, ops::AddAssign};
+use std::{
+    borrow::Cow,
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    ops::AddAssign,
+    sync::{Arc, Mutex},
+};
 
+#[cfg(feature = "rand")]
+use crate::machines::NoisyMachine;
 use crate::{
-    errors::ArenaError,
+    errors::{ArenaError, ConvergenceError, MatchError, PlayerError},
     genetics::GeneticStrategy,
-    machines::Machine,
-    traits::{MachineTrait, MatchTrait, PlayerTrait},
+    machines::{GroupMachine, Machine},
+    players::BuiltinPlayer,
+    traits::{
+        fresh_clone, ArenaPlayer, ExpectedValueMachineTrait, GroupPlayerTrait, MachineTrait,
+        MatchObserver, MatchTrait, PlayerFactory, PlayerTrait, SyncPlayerTrait, TryPlayerTrait,
+    },
 };
 
 /// A structure simulating two people playing a game.
 #[derive(Debug)]
-pub struct Match<T, P1, P2, M = Machine<T>> {
+pub struct Match<P1, P2, T = isize, M = Machine<T>> {
     /// The machine used in the match.
     pub machine: M,
     /// Players of the match.
@@ -19,32 +33,356 @@ pub struct Match<T, P1, P2, M = Machine<T>> {
     pub phantom: PhantomData<T>,
 }
 
-impl<T, P1, P2, M> MatchTrait<T> for Match<T, P1, P2, M>
+impl<T, P1, P2, M> Match<P1, P2, T, M>
 where
-    T: AddAssign<T> + Clone + Default,
+    T: Clone,
     P1: PlayerTrait<T>,
     P2: PlayerTrait<T>,
     M: MachineTrait<T>,
 {
-    fn play(&mut self) {
-        let last_consents = (
-            self.players.0.cooperation_consent(),
-            self.players.1.cooperation_consent(),
-        );
-        let last_rewards = self.machine.play(last_consents).clone();
+    /// Tell the players about the round to be played next.
+    fn observe_round_context(&mut self, planned_rounds: Option<usize>) {
+        let round = self.machine.round_count();
+        self.players.0.observe_round_context(round, planned_rounds);
+        self.players.1.observe_round_context(round, planned_rounds);
+    }
 
-        // broadcast results to players
+    /// Broadcast the results of the round to the players.
+    fn conclude_round(
+        &mut self,
+        last_consents: (bool, bool),
+        last_rewards: (T, T),
+    ) -> RoundOutcome<T> {
         self.players.1.memorize_last_game(
             (last_consents.1, last_consents.0),
             (last_rewards.1.clone(), last_rewards.0.clone()),
         );
         self.players
             .0
-            .memorize_last_game(last_consents, last_rewards);
+            .memorize_last_game(last_consents, last_rewards.clone());
+
+        RoundOutcome {
+            consents: last_consents,
+            rewards: last_rewards,
+        }
+    }
+}
+
+impl<T, P1, P2, M> MatchTrait<T> for Match<P1, P2, T, M>
+where
+    T: AddAssign<T> + Clone + Default,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+    M: MachineTrait<T>,
+{
+    type Outcome = RoundOutcome<T>;
+
+    fn play(&mut self) -> RoundOutcome<T> {
+        let last_consents = (
+            self.players.0.cooperation_consent(),
+            self.players.1.cooperation_consent(),
+        );
+        // the players must see what the machine registered, not what they intended.
+        let (last_consents, last_rewards) = self.machine.play_registered(last_consents);
+        self.conclude_round(last_consents, last_rewards)
+    }
+
+    fn round_count(&self) -> usize {
+        self.machine.round_count()
+    }
+
+    fn standings(&self) -> Vec<(usize, T)> {
+        let scores = self.machine.scores();
+        vec![(0, scores.0), (1, scores.1)]
+    }
+
+    /// Reset the scores of the machine and the memory of the players.
+    fn reset(&mut self) {
+        self.machine.reset_scores();
+        self.players.0.forget_games();
+        self.players.1.forget_games();
+    }
+
+    fn result(&self) -> Option<MatchResult<T>>
+    where
+        T: Ord + Clone,
+    {
+        (self.machine.round_count() > 0).then(|| self.into())
+    }
+
+    fn play_for_rounds(&mut self, rounds: usize) -> Vec<RoundOutcome<T>> {
+        let planned_rounds = Some(self.machine.round_count() + rounds);
+        (0..rounds)
+            .map(|_| {
+                self.observe_round_context(planned_rounds);
+                self.play()
+            })
+            .collect()
+    }
+}
+
+impl<T, P1, P2, M> Match<P1, P2, T, M>
+where
+    T: AddAssign<T> + Clone + Default,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+    M: MachineTrait<T>,
+{
+    /// Same as [`MatchTrait::play_for_rounds`] but the players are not told when the match ends.
+    pub fn play_for_unannounced_rounds(&mut self, rounds: usize) -> Vec<RoundOutcome<T>> {
+        (0..rounds)
+            .map(|_| {
+                self.observe_round_context(None);
+                self.play()
+            })
+            .collect()
+    }
+
+    /// Play the rounds of the schedule as its first pairing (see [`RoundSchedule::rounds_for`]).
+    ///
+    /// The players are told when the match ends unless the schedule hides it (see
+    /// [`RoundSchedule::announces_rounds`]).
+    pub fn play_for_schedule(
+        &mut self,
+        schedule: &RoundSchedule,
+        seed: Option<u64>,
+    ) -> Vec<RoundOutcome<T>> {
+        let rounds = schedule.rounds_for(0, seed);
+        if schedule.announces_rounds() {
+            self.play_for_rounds(rounds)
+        } else {
+            self.play_for_unannounced_rounds(rounds)
+        }
+    }
+}
+
+impl<T, P1, P2, M> Match<P1, P2, T, M>
+where
+    T: Clone,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+    M: ExpectedValueMachineTrait<T>,
+{
+    /// Play the next round with the chances of cooperation instead of the consents.
+    ///
+    /// The machine records the expected rewards which are also given to the players along with
+    /// their consents.
+    pub fn play_expected(&mut self) -> RoundOutcome<T> {
+        let probabilities = (
+            self.players.0.cooperation_probability(),
+            self.players.1.cooperation_probability(),
+        );
+        let last_consents = (
+            self.players.0.cooperation_consent(),
+            self.players.1.cooperation_consent(),
+        );
+        let last_rewards = self.machine.play_expected(probabilities);
+        self.conclude_round(last_consents, last_rewards)
+    }
+
+    /// Play the number of rounds in succession with [`Self::play_expected`].
+    pub fn play_expected_for_rounds(&mut self, rounds: usize) -> Vec<RoundOutcome<T>> {
+        let planned_rounds = Some(self.machine.round_count() + rounds);
+        (0..rounds)
+            .map(|_| {
+                self.observe_round_context(planned_rounds);
+                self.play_expected()
+            })
+            .collect()
+    }
+}
+
+impl<T, P1, P2, M> Match<P1, P2, T, M>
+where
+    T: Clone,
+    P1: TryPlayerTrait<T>,
+    P2: TryPlayerTrait<T>,
+    M: MachineTrait<T>,
+{
+    /// Same as [`MatchTrait::play`] but stops on the first failure of the players.
+    ///
+    /// If a player fails to give its consent, the round is not played at all. Once the machine
+    /// registers the round, it is counted and told to both players even if the first one fails to
+    /// memorize it (the first failure is returned).
+    pub fn try_play(&mut self) -> Result<RoundOutcome<T>, PlayerError> {
+        let consents = (
+            self.players.0.try_cooperation_consent()?,
+            self.players.1.try_cooperation_consent()?,
+        );
+        let (last_consents, last_rewards) = self.machine.play_registered(consents);
+
+        let memorized = (
+            self.players
+                .0
+                .try_memorize_last_game(last_consents, last_rewards.clone()),
+            self.players.1.try_memorize_last_game(
+                (last_consents.1, last_consents.0),
+                (last_rewards.1.clone(), last_rewards.0.clone()),
+            ),
+        );
+        memorized.0.and(memorized.1)?;
+
+        Ok(RoundOutcome {
+            consents: last_consents,
+            rewards: last_rewards,
+        })
+    }
+
+    /// Play the number of rounds in succession with [`Self::try_play`] (stop on the first error).
+    pub fn try_play_for_rounds(
+        &mut self,
+        rounds: usize,
+    ) -> Result<Vec<RoundOutcome<T>>, PlayerError> {
+        let planned_rounds = Some(self.machine.round_count() + rounds);
+        (0..rounds)
+            .map(|_| {
+                let round = self.machine.round_count();
+                self.players
+                    .0
+                    .try_observe_round_context(round, planned_rounds)?;
+                self.players
+                    .1
+                    .try_observe_round_context(round, planned_rounds)?;
+                self.try_play()
+            })
+            .collect()
+    }
+}
+
+impl<T, P1, P2, M> Match<P1, P2, T, M> {
+    /// Get the match notifying the given observer of each round.
+    pub fn with_observer<O: MatchObserver<T>>(self, observer: O) -> ObservedMatch<P1, P2, T, M, O> {
+        ObservedMatch {
+            game: self,
+            observer,
+        }
+    }
+}
+
+/// A [`Match`] notifying an observer of each round (see [`Match::with_observer`]).
+#[derive(Debug)]
+pub struct ObservedMatch<P1, P2, T, M, O> {
+    /// The observed match.
+    pub game: Match<P1, P2, T, M>,
+    /// The observer of the match.
+    pub observer: O,
+}
+
+impl<T, P1, P2, M, O> MatchTrait<T> for ObservedMatch<P1, P2, T, M, O>
+where
+    T: AddAssign<T> + Clone + Default,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+    M: MachineTrait<T>,
+    O: MatchObserver<T>,
+{
+    type Outcome = RoundOutcome<T>;
+
+    fn play(&mut self) -> RoundOutcome<T> {
+        let outcome = self.game.play();
+        self.observer.on_round(&outcome);
+        outcome
+    }
+
+    fn round_count(&self) -> usize {
+        self.game.round_count()
+    }
+
+    fn standings(&self) -> Vec<(usize, T)> {
+        self.game.standings()
+    }
+
+    fn reset(&mut self) {
+        self.game.reset()
+    }
+
+    fn result(&self) -> Option<MatchResult<T>>
+    where
+        T: Ord + Clone,
+    {
+        MatchTrait::result(&self.game)
+    }
+
+    fn play_for_rounds(&mut self, rounds: usize) -> Vec<RoundOutcome<T>> {
+        let planned_rounds = Some(self.game.machine.round_count() + rounds);
+        (0..rounds)
+            .map(|_| {
+                self.game.observe_round_context(planned_rounds);
+                self.play()
+            })
+            .collect()
+    }
+}
+
+/// An event seen by a [`VecRecorder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObserverEvent<T> {
+    /// See [`MatchObserver::on_round`].
+    Round(RoundOutcome<T>),
+    /// See [`MatchObserver::on_pairing_start`].
+    PairingStart(usize, usize),
+    /// See [`MatchObserver::on_pairing_end`].
+    PairingEnd((T, T)),
+    /// See [`MatchObserver::on_generation_end`].
+    GenerationEnd(Vec<usize>, Vec<T>),
+}
+
+/// An observer keeping all the events in memory.
+///
+/// The clones share the same events so one can be given away to be observed through the other.
+#[derive(Debug, Clone)]
+pub struct VecRecorder<T> {
+    events: Arc<Mutex<Vec<ObserverEvent<T>>>>,
+}
+
+impl<T> Default for VecRecorder<T> {
+    fn default() -> Self {
+        Self {
+            events: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone> VecRecorder<T> {
+    /// Get the recorded events so far.
+    pub fn events(&self) -> Vec<ObserverEvent<T>> {
+        self.events
+            .lock()
+            .expect("the recorder is poisoned")
+            .clone()
+    }
+
+    /// Record the given event.
+    fn record(&mut self, event: ObserverEvent<T>) {
+        self.events
+            .lock()
+            .expect("the recorder is poisoned")
+            .push(event);
+    }
+}
+
+impl<T: Clone> MatchObserver<T> for VecRecorder<T> {
+    fn on_round(&mut self, outcome: &RoundOutcome<T>) {
+        self.record(ObserverEvent::Round(outcome.clone()));
+    }
+
+    fn on_pairing_start(&mut self, p1_type: usize, p2_type: usize) {
+        self.record(ObserverEvent::PairingStart(p1_type, p2_type));
+    }
+
+    fn on_pairing_end(&mut self, scores: &(T, T)) {
+        self.record(ObserverEvent::PairingEnd(scores.clone()));
+    }
+
+    fn on_generation_end(&mut self, population: &[usize], scores: &[T]) {
+        self.record(ObserverEvent::GenerationEnd(
+            population.to_vec(),
+            scores.to_vec(),
+        ));
     }
 }
 
-impl<P1, P2> Default for Match<isize, P1, P2>
+impl<P1, P2> Default for Match<P1, P2>
 where
     P1: PlayerTrait<isize> + Default,
     P2: PlayerTrait<isize> + Default,
@@ -58,183 +396,3507 @@ where
     }
 }
 
-/// A place where multiple opponents compete 2 by 2 and get removed and the best multiply.
-pub struct Arena<T: Default + Clone, M = Machine<T>>
+impl<T, M> Match<Box<dyn PlayerTrait<T>>, Box<dyn PlayerTrait<T>>, T, M>
 where
-    T: Clone + Default,
+    T: AddAssign<T> + Clone + Default,
     M: MachineTrait<T>,
 {
-    /// The rule of the base match for each 1v1 competition.
-    machine: M,
-    /// What type of players are present in the game (assumed forgotten version).
-    player_constructors: Vec<Box<dyn PlayerTrait<T>>>,
-    /// Players competing in the arena (holds the ID of `player_types`).
-    players: Vec<usize>,
-    /// What's every player's score.
-    scores: Vec<T>,
-    /// Rounds per play for each two opponents.
-    rounds: usize,
-    /// How to remove or multiply winners between each play (if needed).
-    strategy: GeneticStrategy,
+    /// Get a match of the players which has already played the given rounds.
+    ///
+    /// See [`MatchBuilder`] for a match to play round by round. Returns Err if the rounds is zero
+    /// or if both players are the same player.
+    pub fn played(
+        machine: M,
+        p1: Box<dyn PlayerTrait<T>>,
+        p2: Box<dyn PlayerTrait<T>>,
+        rounds: usize,
+    ) -> Result<Self, MatchError> {
+        if rounds == 0 {
+            return Err(MatchError::ZeroRounds);
+        }
+        if same_player(&*p1, &*p2) {
+            return Err(MatchError::IdenticalPlayers);
+        }
+
+        let mut game = Self {
+            machine,
+            players: (p1, p2),
+            phantom: Default::default(),
+        };
+        game.play_for_rounds(rounds);
+        Ok(game)
+    }
 }
 
-impl<T, M> Arena<T, M>
+/// Whether both players are at the same address.
+///
+/// Players without any state (e.g. [`crate::players::AllCheat`]) share a dangling address when
+/// boxed hence they are never considered the same.
+fn same_player<T>(p1: &dyn PlayerTrait<T>, p2: &dyn PlayerTrait<T>) -> bool {
+    std::mem::size_of_val(p1) != 0
+        && std::ptr::eq(
+            p1 as *const dyn PlayerTrait<T> as *const u8,
+            p2 as *const dyn PlayerTrait<T> as *const u8,
+        )
+}
+
+impl<T, P1, P2, M> Match<P1, P2, T, M>
 where
-    T: Clone + Default + AddAssign<T>,
+    T: Clone,
     M: MachineTrait<T>,
 {
-    /// Returns the arena or Err if players not in `0..player_constructors.len()`.
-    pub fn new(
-        machine: M,
-        player_construtors: Vec<Box<dyn PlayerTrait<T>>>,
-        players: Vec<usize>,
-        rounds: usize,
-        strategy: GeneticStrategy,
-    ) -> Result<Self, ArenaError> {
-        for &i in players.iter() {
-            if i >= player_construtors.len() {
-                return Err(ArenaError::UnknownPlayer);
-            }
-        }
-
-        // make sure they are clean and forgotten everything in the past (to clone).
-        let forgotten = player_construtors
-            .into_iter()
-            .map(|mut i| {
-                i.forget_games();
-                i
-            })
-            .collect();
+    /// Get a new match with the players in the reversed order and the machine reset.
+    ///
+    /// Useful for asymmetrical matrices in which the order of players matters.
+    /// Note that the players are not told to forget their games.
+    pub fn swap_players(self) -> Match<P2, P1, T, M> {
+        let mut machine = self.machine;
+        machine.reset_scores();
 
-        Ok(Self {
-            player_constructors: forgotten,
-            scores: Default::default(),
-            strategy,
+        Match {
             machine,
-            rounds,
-            players,
-        })
+            players: (self.players.1, self.players.0),
+            phantom: Default::default(),
+        }
     }
 }
 
-impl<T, M> MatchTrait<T> for Arena<T, M>
+/// What happened in a single round of a [`Match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundOutcome<T> {
+    /// The consents of the players as given to the machine.
+    pub consents: (bool, bool),
+    /// The rewards of the players as registered by the machine.
+    pub rewards: (T, T),
+}
+
+/// The outcome of a [`Match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchResult<T> {
+    /// The final scores of both players.
+    pub scores: (T, T),
+    /// How many rounds were played in the match.
+    pub rounds_played: usize,
+    /// The index of the player with the higher score (`None` if tied).
+    pub winner: Option<u8>,
+}
+
+impl<T, P1, P2, M> From<&Match<P1, P2, T, M>> for MatchResult<T>
 where
-    T: Clone + Default + AddAssign<T> + Ord,
+    T: Ord + Clone,
     M: MachineTrait<T>,
 {
-    fn play(&mut self) {
-        // reset scores.
-        self.scores = vec![Default::default(); self.players.len()];
+    fn from(value: &Match<P1, P2, T, M>) -> Self {
+        let scores = value.machine.scores();
+        let winner = match scores.0.cmp(&scores.1) {
+            Ordering::Greater => Some(0),
+            Ordering::Less => Some(1),
+            Ordering::Equal => None,
+        };
 
-        for i in 0..self.players.len() {
-            for j in (i + 1)..self.players.len() {
-                // get both players cleared.
-                let p1 = self.player_constructors[self.players[i]].clone();
-                let p2 = self.player_constructors[self.players[j]].clone();
-
-                // reset everything and make a match.
-                self.machine.reset_scores();
-
-                // play the rounds
-                let ovo_results = {
-                    let mut ovo = Match::<T, _, _, _> {
-                        machine: &mut self.machine,
-                        players: (p1, p2),
-                        phantom: Default::default(),
-                    };
-                    for _ in 0..self.rounds {
-                        ovo.play();
-                    }
-                    ovo.machine.scores()
-                };
+        Self {
+            scores,
+            rounds_played: value.machine.round_count(),
+            winner,
+        }
+    }
+}
 
-                // memorize the results
-                self.scores[i] += ovo_results.0;
-                self.scores[j] += ovo_results.1;
-            }
+/// A helper to construct a [`Match`] without setting every field by hand.
+///
+/// If [`Self::rounds`] is given, the built match has already played that many rounds.
+#[derive(Debug)]
+pub struct MatchBuilder<P1, P2, T = isize, M = Machine<T>> {
+    machine: Option<M>,
+    players: Option<(P1, P2)>,
+    rounds: Option<usize>,
+    phantom: PhantomData<T>,
+}
+
+impl<T, P1, P2, M> MatchBuilder<P1, P2, T, M> {
+    /// Create an empty builder (machine and players must be set before building).
+    pub fn new() -> Self {
+        Self {
+            machine: None,
+            players: None,
+            rounds: None,
+            phantom: Default::default(),
         }
+    }
 
-        // The best type of players (best at the end of the array).
-        // TODO add other multiplication strategies for the next generation.
-        let sorted_types = {
-            let mut t = self
-                .scores
-                .clone()
-                .into_iter()
-                .enumerate()
-                .map(|(t, v)| (self.players[t], v))
-                .collect::<Vec<(usize, T)>>();
-            t.sort_by_key(|(_, v)| v.clone());
-            t.into_iter().map(|(t, _)| t).collect::<Vec<usize>>()
+    /// Set the machine of the match.
+    pub fn machine(mut self, machine: M) -> Self {
+        self.machine = Some(machine);
+        self
+    }
+
+    /// Set the players of the match.
+    pub fn players(mut self, p1: P1, p2: P2) -> Self {
+        self.players = Some((p1, p2));
+        self
+    }
+
+    /// Set the number of rounds to play right after building.
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = Some(rounds);
+        self
+    }
+}
+
+impl<T, P1, P2, M> MatchBuilder<P1, P2, T, M>
+where
+    T: AddAssign<T> + Clone + Default,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+    M: MachineTrait<T>,
+{
+    /// Build the match (played for the given rounds if any).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the machine or the players are not set.
+    pub fn build(self) -> Match<P1, P2, T, M> {
+        let mut game = Match {
+            machine: self.machine.expect("the machine of the match is not set"),
+            players: self.players.expect("the players of the match are not set"),
+            phantom: Default::default(),
         };
 
-        self.players = self.strategy.apply_to_vec(sorted_types);
+        if let Some(rounds) = self.rounds {
+            game.play_for_rounds(rounds);
+        }
+
+        game
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::players::*;
+/// What happened in a single round of a [`GroupMatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupRoundOutcome<T> {
+    /// The consents of the players in order.
+    pub consents: Vec<bool>,
+    /// The rewards of the players in order.
+    pub rewards: Vec<T>,
+}
 
-    fn test_arena(
-        copycats: (usize, isize),
-        allcheaters: (usize, isize),
-        allcooperates: (usize, isize),
-        grudgers: (usize, isize),
-        detectives: (usize, isize),
-        kindcopycats: (usize, isize),
-        simpletons: (usize, isize),
-        next_gen_players: Vec<usize>,
-    ) {
-        let mut scores = vec![];
+/// A structure simulating any number of people playing a game together.
+pub struct GroupMatch<T> {
+    /// The machine used in the match.
+    pub machine: GroupMachine<T>,
+    /// Players of the match.
+    pub players: Vec<Box<dyn GroupPlayerTrait<T>>>,
+    /// How many rounds have been played in this match so far.
+    pub rounds_played: usize,
+}
 
-        let mut players: Vec<_> = vec![];
-        for (i, c) in vec![
-            copycats.0,
-            allcheaters.0,
-            allcooperates.0,
-            grudgers.0,
-            detectives.0,
-            kindcopycats.0,
-            simpletons.0,
-        ]
-        .into_iter()
-        .enumerate()
-        {
-            players.append(&mut vec![i; c]);
+impl<T> GroupMatch<T> {
+    pub fn new(machine: GroupMachine<T>, players: Vec<Box<dyn GroupPlayerTrait<T>>>) -> Self {
+        Self {
+            machine,
+            players,
+            rounds_played: 0,
         }
+    }
+}
 
-        let mut arena = Arena {
-            machine: Machine::default(),
-            rounds: 10,
-            scores: vec![0; players.len()],
-            player_constructors: vec![
-                Box::new(CopyCat::default()),
-                Box::new(AllCheat::default()),
-                Box::new(AllCooperate::default()),
-                Box::new(Grudger::default()),
+impl<T: AddAssign<T> + Clone + Default> MatchTrait<T> for GroupMatch<T> {
+    type Outcome = GroupRoundOutcome<T>;
+
+    fn play(&mut self) -> GroupRoundOutcome<T> {
+        let consents = self
+            .players
+            .iter()
+            .map(|p| p.cooperation_consent())
+            .collect::<Vec<_>>();
+        let rewards = self.machine.play(&consents);
+
+        // broadcast results to players
+        let cooperators = consents.iter().filter(|&&c| c).count();
+        let others = self.players.len().saturating_sub(1);
+        for ((player, &consent), reward) in self.players.iter_mut().zip(&consents).zip(&rewards) {
+            let other_cooperators = cooperators - usize::from(consent);
+            player.memorize_last_group_game(consent, other_cooperators, others, reward.clone());
+        }
+
+        self.rounds_played += 1;
+        GroupRoundOutcome { consents, rewards }
+    }
+
+    fn standings(&self) -> Vec<(usize, T)> {
+        self.machine.scores.iter().cloned().enumerate().collect()
+    }
+
+    fn reset(&mut self) {
+        self.machine.reset_scores();
+        for player in self.players.iter_mut() {
+            player.forget_games();
+        }
+        self.rounds_played = 0;
+    }
+}
+
+impl<P1, P2> Default for MatchBuilder<P1, P2> {
+    fn default() -> Self {
+        Self::new().machine(Default::default())
+    }
+}
+
+/// The pair of players (by index in the population), their scores, rounds (if recorded) and
+/// the number of rounds played.
+type PairResult<T> = (usize, usize, (T, T), Vec<RoundOutcome<T>>, usize);
+
+/// The scores, the rounds (if recorded) and the players of a 1v1 match after playing it.
+type PlayedMatch<T, P1, P2> = ((T, T), Vec<RoundOutcome<T>>, (P1, P2));
+
+/// The rounds of a pairing and whether the players are told about them.
+#[derive(Debug, Clone, Copy)]
+struct PairingRounds {
+    count: usize,
+    announced: bool,
+}
+
+impl From<usize> for PairingRounds {
+    fn from(count: usize) -> Self {
+        Self {
+            count,
+            announced: true,
+        }
+    }
+}
+
+/// Play a 1v1 match between the given players on the machine and return the scores.
+///
+/// If a seed is given, the players and the machine are reseeded with it before playing. Each
+/// consent is flipped by the chance of the noise (requires feature "rand", otherwise ignored).
+/// The outcome of each round is also returned if recorded, then the players (to keep their
+/// memory if needed).
+#[cfg_attr(not(feature = "rand"), allow(unused_variables))]
+fn play_match<T, M, P1, P2>(
+    machine: &mut M,
+    mut p1: P1,
+    mut p2: P2,
+    rounds: PairingRounds,
+    seed: Option<u64>,
+    noise: f32,
+    record_rounds: bool,
+) -> PlayedMatch<T, P1, P2>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+{
+    // reset everything and make a match.
+    machine.reset_scores();
+    if let Some(seed) = seed {
+        p1.reseed(seed);
+        p2.reseed(seed.wrapping_add(1));
+        machine.reseed(seed.wrapping_add(2));
+    }
+
+    #[cfg(feature = "rand")]
+    if noise > 0.0 {
+        use rand::SeedableRng;
+
+        let mut noisy = NoisyMachine::new(machine, noise);
+        // not reseeding the base again so the machine plays as it would without noise
+        noisy.rng = seed.map(|seed| {
+            rand::rngs::StdRng::seed_from_u64(
+                seed.wrapping_add(2).wrapping_mul(0x9E37_79B9_7F4A_7C15),
+            )
+        });
+        return play_rounds(&mut noisy, p1, p2, rounds, record_rounds);
+    }
+    play_rounds(machine, p1, p2, rounds, record_rounds)
+}
+
+/// Play the rounds of a 1v1 match (see [`play_match`]).
+fn play_rounds<T, M, P1, P2>(
+    machine: &mut M,
+    mut p1: P1,
+    mut p2: P2,
+    rounds: PairingRounds,
+    record_rounds: bool,
+) -> PlayedMatch<T, P1, P2>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+    P1: PlayerTrait<T>,
+    P2: PlayerTrait<T>,
+{
+    p1.notify_match_start();
+    p2.notify_match_start();
+    let mut ovo = Match::<_, _, T, _> {
+        machine,
+        players: (p1, p2),
+        phantom: Default::default(),
+    };
+    // same as `play_for_rounds` (or unannounced) but the outcomes are kept only if recorded
+    let planned_rounds = rounds.announced.then_some(rounds.count);
+    let mut outcomes = vec![];
+    for _ in 0..rounds.count {
+        ovo.observe_round_context(planned_rounds);
+        let outcome = ovo.play();
+        if record_rounds {
+            outcomes.push(outcome);
+        }
+    }
+    let scores = ovo.machine.scores();
+    ovo.players.0.notify_match_end(scores.clone());
+    ovo.players
+        .1
+        .notify_match_end((scores.1.clone(), scores.0.clone()));
+    (scores, outcomes, ovo.players)
+}
+
+/// Get a distinct seed for each match (by index) from the seed of a generation (if any).
+fn match_seed(generation_seed: Option<u64>, index: usize) -> Option<u64> {
+    // each match uses three consecutive seeds
+    generation_seed.map(|seed| seed.wrapping_add(3 * index as u64))
+}
+
+/// Get the seed to draw the rounds of a match from its seed (if any).
+fn schedule_seed(match_seed: Option<u64>) -> Option<u64> {
+    // not any of the seeds of the players and the machine of the match
+    match_seed.map(|seed| seed.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Count how many times each type (ID) is present in the population.
+fn type_frequencies(population: &[usize]) -> HashMap<usize, usize> {
+    let mut frequencies = HashMap::new();
+    for &i in population {
+        *frequencies.entry(i).or_default() += 1;
+    }
+    frequencies
+}
+
+/// Get the normalized Herfindahl-Hirschman Index of the population (0.0 if empty).
+fn diversity(population: &[usize]) -> f64 {
+    if population.is_empty() {
+        return 0.0;
+    }
+
+    // summed as integers so the result does not depend on the order of the types
+    let total = population.len() as f64;
+    let squares: usize = type_frequencies(population)
+        .values()
+        .map(|&count| count * count)
+        .sum();
+    1.0 - squares as f64 / (total * total)
+}
+
+/// Whether the population is stable or cycles (see [`census_stability`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stability {
+    Stable,
+    Cycle,
+}
+
+/// Detect whether the last censuses are unchanged or alternate between two states (a period-2
+/// cycle) for `patience` generations in a row (`None` if neither).
+fn census_stability(censuses: &[Vec<usize>], patience: usize) -> Option<Stability> {
+    if patience == 0 {
+        return Some(Stability::Stable);
+    }
+
+    let n = censuses.len();
+    if n > patience
+        && censuses[n - patience - 1..]
+            .windows(2)
+            .all(|w| w[0] == w[1])
+    {
+        return Some(Stability::Stable);
+    }
+    if n > patience + 1
+        && censuses[n - patience - 2..]
+            .windows(3)
+            .all(|w| w[0] == w[2] && w[0] != w[1])
+    {
+        return Some(Stability::Cycle);
+    }
+    None
+}
+
+/// How the players of an [`Arena`] are paired to play in each generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum PairingMode {
+    /// Every two players play a match (the number of matches grows quadratically).
+    #[default]
+    RoundRobin,
+    /// Shuffle the population and play the adjacent players for the given pairing rounds
+    /// (requires feature "rand").
+    ///
+    /// The leftover player of an odd population sits out the pairing round (scoring nothing).
+    /// The shuffles are seeded by [`Arena::with_seed`] if given.
+    #[cfg(feature = "rand")]
+    RandomPairs {
+        /// How many times the population is shuffled and paired in each generation.
+        rounds_of_pairing: usize,
+    },
+    /// Every player picks `k` distinct random opponents (requires feature "rand").
+    ///
+    /// `k` is clamped to the population minus one and two players picking each other play only
+    /// once, so a player may play more than `k` matches. Since the players play different numbers
+    /// of matches, the genetic strategy ranks them by their average score of each match (see
+    /// [`Arena::matches_played`]). The picks are seeded by [`Arena::with_seed`] if given.
+    #[cfg(feature = "rand")]
+    SampleOpponents {
+        /// How many opponents each player picks.
+        k: usize,
+    },
+}
+
+impl PairingMode {
+    /// Get the number of the matches played in a generation of the given population.
+    ///
+    /// For `SampleOpponents` this is the most matches possible (no mutual picks).
+    pub fn match_count(&self, population: usize) -> usize {
+        let round_robin = population * population.saturating_sub(1) / 2;
+        match *self {
+            Self::RoundRobin => round_robin,
+            #[cfg(feature = "rand")]
+            Self::RandomPairs { rounds_of_pairing } => rounds_of_pairing * (population / 2),
+            #[cfg(feature = "rand")]
+            Self::SampleOpponents { k } => {
+                (population * k.min(population.saturating_sub(1))).min(round_robin)
+            }
+        }
+    }
+
+    /// Whether the players are ranked by the average score of each match instead of the total.
+    fn ranks_per_match(&self) -> bool {
+        match *self {
+            #[cfg(feature = "rand")]
+            Self::SampleOpponents { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// How long the players of an [`Arena`] remember the games they played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryPolicy {
+    /// Every pairing is played by freshly built players (the classic game).
+    #[default]
+    ForgetEachPairing,
+    /// Each player plays all its pairings of a generation with the same memory and is built
+    /// again for the next generation.
+    PersistWithinGeneration,
+    /// Each player keeps its memory for as long as it survives the genetic strategy (the added
+    /// players are freshly built).
+    PersistAcrossGenerations,
+}
+
+/// How many rounds each pairing of an [`Arena`] plays.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RoundSchedule {
+    /// Every pairing plays the given rounds (told to the players).
+    Fixed(usize),
+    /// Every pairing plays a round and continues by the given chance after each round, up to
+    /// the cap (requires feature "rand").
+    ///
+    /// The players are not told the rounds so they can not plan for the last one. The rounds are
+    /// drawn before playing and seeded by [`Arena::with_seed`] if given.
+    #[cfg(feature = "rand")]
+    Geometric {
+        /// The chance of playing another round (`0.0..=1.0`).
+        continue_probability: f64,
+        /// The most rounds of a pairing.
+        cap: usize,
+    },
+    /// The pairings of each generation play the given rounds in order (repeated if there are
+    /// more pairings).
+    PerPairing(Vec<usize>),
+}
+
+impl RoundSchedule {
+    /// Returns Err if a pairing may play no rounds or the chance to continue is not in
+    /// `0.0..=1.0`.
+    pub fn validate(&self) -> Result<(), ArenaError> {
+        match self {
+            Self::Fixed(0) => Err(ArenaError::RoundCountZero),
+            #[cfg(feature = "rand")]
+            Self::Geometric { cap: 0, .. } => Err(ArenaError::RoundCountZero),
+            #[cfg(feature = "rand")]
+            Self::Geometric {
+                continue_probability,
+                ..
+            } if !(0.0..=1.0).contains(continue_probability) => {
+                Err(ArenaError::InvalidContinueProbability)
+            }
+            Self::PerPairing(rounds) if rounds.is_empty() || rounds.contains(&0) => {
+                Err(ArenaError::RoundCountZero)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the most rounds a pairing may play.
+    pub fn max_rounds(&self) -> usize {
+        match self {
+            Self::Fixed(rounds) => *rounds,
+            #[cfg(feature = "rand")]
+            Self::Geometric { cap, .. } => *cap,
+            Self::PerPairing(rounds) => rounds.iter().copied().max().unwrap_or_default(),
+        }
+    }
+
+    /// Whether the players are told how many rounds they play.
+    pub fn announces_rounds(&self) -> bool {
+        match self {
+            #[cfg(feature = "rand")]
+            Self::Geometric { .. } => false,
+            _ => true,
+        }
+    }
+
+    /// Get the rounds of a pairing by its order in the generation (drawn from the seed if given
+    /// and needed).
+    #[cfg_attr(not(feature = "rand"), allow(unused_variables))]
+    pub fn rounds_for(&self, pairing: usize, seed: Option<u64>) -> usize {
+        match self {
+            Self::Fixed(rounds) => *rounds,
+            #[cfg(feature = "rand")]
+            Self::Geometric {
+                continue_probability,
+                cap,
+            } => {
+                use rand::{Rng, SeedableRng};
+
+                let mut rng = match seed {
+                    Some(seed) => rand::rngs::StdRng::seed_from_u64(seed),
+                    None => rand::rngs::StdRng::from_entropy(),
+                };
+                let mut rounds = 1;
+                while rounds < *cap && rng.gen::<f64>() < *continue_probability {
+                    rounds += 1;
+                }
+                rounds
+            }
+            Self::PerPairing(rounds) => rounds[pairing % rounds.len()],
+        }
+    }
+}
+
+/// Compare the average scores of each match (`a / a_matches` to `b / b_matches`).
+///
+/// The totals are cross multiplied (by repeated addition) instead of divided.
+fn cmp_per_match<T>(a: &T, a_matches: usize, b: &T, b_matches: usize) -> Ordering
+where
+    T: Clone + Default + AddAssign<T> + Ord,
+{
+    if a_matches == b_matches {
+        return a.cmp(b);
+    }
+    let times = |value: &T, n: usize| {
+        let mut product = T::default();
+        for _ in 0..n {
+            product += value.clone();
+        }
+        product
+    };
+    times(a, b_matches).cmp(&times(b, a_matches))
+}
+
+/// The state of an [`Arena`] right after playing a generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationSnapshot<T> {
+    /// The index of the played generation (starting from 0).
+    pub generation_index: usize,
+    /// The players of the played generation (each with the score of the same index in
+    /// `scores`).
+    pub players: Vec<usize>,
+    /// The players of the next generation (the IDs of player constructors).
+    pub population: Vec<usize>,
+    /// The scores each player of the played generation acquired.
+    pub scores: Vec<T>,
+}
+
+/// Summary of the scores and the population of a played generation in an [`Arena`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationStats<T> {
+    /// The lowest score of the generation.
+    pub min_score: T,
+    /// The highest score of the generation.
+    pub max_score: T,
+    /// The middle score of the generation (the lower one if the population is even).
+    pub median_score: T,
+    /// The most frequent type (the lowest ID if tied, `None` if empty).
+    pub dominant_type: Option<usize>,
+    /// The normalized Herfindahl-Hirschman Index of the population.
+    pub diversity: f64,
+}
+
+impl<T: Ord + Clone + Default> GenerationStats<T> {
+    /// Summarize a generation given the players and the score of each.
+    pub fn new(players: &[usize], scores: &[T]) -> Self {
+        let mut sorted = scores.to_vec();
+        sorted.sort();
+        let median_score = if sorted.is_empty() {
+            Default::default()
+        } else {
+            sorted[(sorted.len() - 1) / 2].clone()
+        };
+
+        Self {
+            min_score: sorted.first().cloned().unwrap_or_default(),
+            max_score: sorted.last().cloned().unwrap_or_default(),
+            median_score,
+            dominant_type: type_frequencies(players)
+                .into_iter()
+                .max_by_key(|&(i, count)| (count, Reverse(i)))
+                .map(|(i, _)| i),
+            diversity: diversity(players),
+        }
+    }
+}
+
+/// Report of a played generation in an [`Arena`] by type (see [`Arena::play_reported`]).
+///
+/// The vectors are indexed by the IDs of the player constructors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationReport<T> {
+    /// The number of players of each type in the played generation.
+    pub census_before: Vec<usize>,
+    /// The number of players of each type in the next generation (after the selection).
+    pub census_after: Vec<usize>,
+    /// The sum of the scores of the players of each type.
+    pub total_scores: Vec<T>,
+    /// The average score of the players of each type (0.0 for the types with no players).
+    pub average_scores: Vec<f64>,
+    /// The type and the score of a player with the highest score (`None` if empty).
+    pub best: Option<(usize, T)>,
+    /// The type and the score of a player with the lowest score (`None` if empty).
+    pub worst: Option<(usize, T)>,
+    /// The number of the matches played (one for every two players).
+    pub pairings: usize,
+    /// The number of the rounds played in all the matches (see [`Arena::rounds_played`]).
+    pub rounds: usize,
+    /// The chance of flipping each consent in the matches (see [`Arena::noise`]).
+    pub noise: f32,
+}
+
+impl<T: fmt::Display> fmt::Display for GenerationReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "type  before  after       total     average")?;
+        for (i, total) in self.total_scores.iter().enumerate() {
+            writeln!(
+                f,
+                "{i:>4}  {:>6}  {:>5}  {:>10}  {:>10.2}",
+                self.census_before[i],
+                self.census_after[i],
+                total.to_string(),
+                self.average_scores[i],
+            )?;
+        }
+        if let (Some((best_type, best)), Some((worst_type, worst))) = (&self.best, &self.worst) {
+            writeln!(
+                f,
+                "best: {best} (type {best_type}), worst: {worst} (type {worst_type})"
+            )?;
+        }
+        write!(
+            f,
+            "pairings: {}, rounds: {}, noise: {}",
+            self.pairings, self.rounds, self.noise
+        )
+    }
+}
+
+/// The populations and the scores of the generations played in an [`Arena`] (see
+/// [`Arena::play_generations`]).
+///
+/// The censuses are the number of players of each type (indexed by the IDs of the constructors).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationHistory<T> {
+    /// The census of the population before the first played generation.
+    pub initial_census: Vec<usize>,
+    /// The census of the next generation after each played generation (after the selection).
+    pub censuses: Vec<Vec<usize>>,
+    /// The scores each player acquired in each played generation.
+    pub scores: Vec<Vec<T>>,
+}
+
+impl<T> SimulationHistory<T> {
+    /// Get the census after the last played generation (the initial one if none is played).
+    pub fn final_census(&self) -> &[usize] {
+        self.censuses.last().unwrap_or(&self.initial_census)
+    }
+
+    /// Get the index of the generation (starting from 0) after which the type has no players.
+    ///
+    /// Returns `None` if the type never went extinct in the played generations (including the
+    /// types with no players from the start).
+    pub fn type_went_extinct(&self, type_id: usize) -> Option<usize> {
+        let mut alive = self.initial_census.get(type_id).copied().unwrap_or(0) > 0;
+        for (generation, census) in self.censuses.iter().enumerate() {
+            let count = census.get(type_id).copied().unwrap_or(0);
+            if alive && count == 0 {
+                return Some(generation);
+            }
+            alive = count > 0;
+        }
+        None
+    }
+
+    /// Get the most frequent type after each played generation (the lowest ID if tied, `None` if
+    /// empty).
+    pub fn dominant_type_per_generation(&self) -> Vec<Option<usize>> {
+        self.censuses
+            .iter()
+            .map(|census| {
+                census
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &count)| count > 0)
+                    .max_by_key(|&(i, &count)| (count, Reverse(i)))
+                    .map(|(i, _)| i)
+            })
+            .collect()
+    }
+}
+
+/// How [`Arena::play_until_stable`] stopped (the censuses are indexed by the IDs of constructors).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StableOutcome {
+    /// The census did not change for the given patience.
+    Stable {
+        /// The number of the played generations.
+        generations: usize,
+        /// The census of the stable population.
+        census: Vec<usize>,
+    },
+    /// The census alternated between two states (a period-2 cycle) for the given patience.
+    Cycle {
+        /// The number of the played generations.
+        generations: usize,
+        /// The census after the last played generation.
+        census: Vec<usize>,
+        /// The census alternating with [`Self::Cycle::census`].
+        other_census: Vec<usize>,
+    },
+    /// The maximum generations were played without the population stabilizing.
+    MaxGenerationsReached {
+        /// The number of the played generations.
+        generations: usize,
+        /// The census after the last played generation.
+        census: Vec<usize>,
+    },
+}
+
+impl StableOutcome {
+    /// Get the number of the played generations.
+    pub fn generations(&self) -> usize {
+        match self {
+            Self::Stable { generations, .. }
+            | Self::Cycle { generations, .. }
+            | Self::MaxGenerationsReached { generations, .. } => *generations,
+        }
+    }
+
+    /// Get the census after the last played generation.
+    pub fn census(&self) -> &[usize] {
+        match self {
+            Self::Stable { census, .. }
+            | Self::Cycle { census, .. }
+            | Self::MaxGenerationsReached { census, .. } => census,
+        }
+    }
+}
+
+/// A place where multiple opponents compete 2 by 2 and get removed and the best multiply.
+///
+/// The players are boxed as `P` which is either `dyn PlayerTrait<T>` or the thread-safe
+/// `dyn SyncPlayerTrait<T>` (see [`ArenaPlayer`] and [`SendArena`]).
+pub struct Arena<T: Default + Clone = isize, M = Machine<T>, P = dyn PlayerTrait<T>>
+where
+    T: Clone + Default,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+{
+    /// The rule of the base match for each 1v1 competition.
+    machine: M,
+    /// Builds the players of each type present in the game.
+    player_constructors: Vec<Box<P::Factory>>,
+    /// The name of each player type (asked once from its factory at construction).
+    constructor_names: Vec<String>,
+    /// Players competing in the arena (holds the ID of `player_types`).
+    players: Vec<usize>,
+    /// Players of the arena at construction (to reset to).
+    initial_players: Vec<usize>,
+    /// Players of the last played generation (each with the score of the same index in `scores`).
+    last_players: Vec<usize>,
+    /// The summary of each played generation.
+    generation_history: Vec<GenerationStats<T>>,
+    /// What's every player's score.
+    scores: Vec<T>,
+    /// How many matches every player played in the last generation.
+    matches_played: Vec<usize>,
+    /// How many rounds every player played in the last generation.
+    rounds_played: Vec<usize>,
+    /// How many rounds each two opponents play.
+    round_schedule: RoundSchedule,
+    /// How to remove or multiply winners between each play (if needed).
+    strategy: GeneticStrategy,
+    /// How the players are paired in each generation.
+    pairing_mode: PairingMode,
+    /// How long the players remember their games.
+    memory_policy: MemoryPolicy,
+    /// The player of each slot of the population which keeps its memory between pairings
+    /// (empty if the players forget each pairing, `None` while playing a match).
+    live_players: Vec<Option<Box<P>>>,
+    /// The seed to reseed players and machines with for reproducible generations.
+    seed: Option<u64>,
+    /// The chance of flipping each consent in the matches (see [`Arena::with_noise`]).
+    noise: f32,
+    /// The types and the scores of every played match (if recorded).
+    matchups: Option<Vec<(usize, usize, T, T)>>,
+    /// Gets notified of the pairings and generations played.
+    observer: Option<Box<P::Observer>>,
+    /// The built-in player types if the constructors are built from them (see
+    /// [`Arena::from_roster`]).
+    roster: Option<Vec<BuiltinPlayer>>,
+}
+
+/// An [`Arena`] which can be moved or shared between threads (e.g. to shard the pairings).
+///
+/// Its players, factories and observer are thread-safe (the "parallel" feature also plays its
+/// pairings in parallel with `Arena::play_parallel`).
+pub type SendArena<T = isize, M = Machine<T>> = Arena<T, M, dyn SyncPlayerTrait<T>>;
+
+impl<T, M> Arena<T, M>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+{
+    /// Returns the arena or Err if any of the inputs is empty (or zero rounds), players not in
+    /// `0..player_constructors.len()` or the strategy does not fit the players.
+    ///
+    /// The given players are prototypes cloned (and forgotten) for each match.
+    pub fn new(
+        machine: M,
+        player_construtors: Vec<Box<dyn PlayerTrait<T>>>,
+        players: Vec<usize>,
+        rounds: usize,
+        strategy: GeneticStrategy,
+    ) -> Result<Self, ArenaError>
+    where
+        T: 'static,
+    {
+        Self::from_prototypes(machine, player_construtors, players, rounds, strategy)
+    }
+
+    /// Same as [`Self::new`] but builds the players of each match with the given factories.
+    pub fn with_factories(
+        machine: M,
+        factories: Vec<Box<dyn PlayerFactory<T>>>,
+        players: Vec<usize>,
+        rounds: usize,
+        strategy: GeneticStrategy,
+    ) -> Result<Self, ArenaError> {
+        Self::from_factories(machine, factories, players, rounds, strategy)
+    }
+
+    /// Get a builder to construct the arena step by step.
+    pub fn builder() -> ArenaBuilder<T, M> {
+        ArenaBuilder::default()
+    }
+}
+
+impl<M: MachineTrait<isize>> Arena<isize, M> {
+    /// Same as [`Self::new`] but with built-in player types which are remembered for
+    /// serialization (see `Self::export_state` with the "serde" feature).
+    pub fn from_roster(
+        machine: M,
+        roster: Vec<BuiltinPlayer>,
+        players: Vec<usize>,
+        rounds: usize,
+        strategy: GeneticStrategy,
+    ) -> Result<Self, ArenaError> {
+        let factories = roster
+            .iter()
+            .map(|p| Box::new(Box::new(p.clone()) as Box<dyn PlayerTrait<isize>>) as _)
+            .collect();
+        let mut arena = Self::with_factories(machine, factories, players, rounds, strategy)?;
+        arena.roster = Some(roster);
+        Ok(arena)
+    }
+}
+
+impl<T, M, P> Arena<T, M, P>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+    Box<P>: PlayerTrait<T>,
+{
+    /// Same as [`Arena::new`] but for any boxed players (e.g. the thread-safe
+    /// [`crate::traits::SyncPlayerTrait`] players).
+    pub fn from_prototypes(
+        machine: M,
+        prototypes: Vec<Box<P>>,
+        players: Vec<usize>,
+        rounds: usize,
+        strategy: GeneticStrategy,
+    ) -> Result<Self, ArenaError>
+    where
+        T: 'static,
+    {
+        let factories = prototypes.into_iter().map(P::prototype_factory).collect();
+        Self::from_factories(machine, factories, players, rounds, strategy)
+    }
+
+    /// Same as [`Arena::with_factories`] but for the factories of any boxed players.
+    pub fn from_factories(
+        machine: M,
+        factories: Vec<Box<P::Factory>>,
+        players: Vec<usize>,
+        rounds: usize,
+        strategy: GeneticStrategy,
+    ) -> Result<Self, ArenaError> {
+        if players.is_empty() {
+            return Err(ArenaError::EmptyPlayers);
+        }
+        if factories.is_empty() {
+            return Err(ArenaError::EmptyConstructors);
+        }
+        if rounds == 0 {
+            return Err(ArenaError::RoundCountZero);
+        }
+        for &i in players.iter() {
+            if i >= factories.len() {
+                return Err(ArenaError::UnknownPlayer);
+            }
+        }
+        strategy.validate(players.len())?;
+
+        Ok(Self {
+            constructor_names: factories
+                .iter()
+                .map(|f| f.player_name().into_owned())
+                .collect(),
+            player_constructors: factories,
+            scores: Default::default(),
+            matches_played: Default::default(),
+            rounds_played: Default::default(),
+            strategy,
+            machine,
+            round_schedule: RoundSchedule::Fixed(rounds),
+            initial_players: players.clone(),
+            last_players: Default::default(),
+            generation_history: Default::default(),
+            players,
+            pairing_mode: PairingMode::RoundRobin,
+            memory_policy: MemoryPolicy::ForgetEachPairing,
+            live_players: Default::default(),
+            seed: None,
+            noise: 0.0,
+            matchups: None,
+            observer: None,
+            roster: None,
+        })
+    }
+
+    /// Restore the players and scores to the state at construction.
+    ///
+    /// The machine and the strategy are not changed.
+    pub fn reset(&mut self) {
+        self.scores = Default::default();
+        self.matches_played = Default::default();
+        self.rounds_played = Default::default();
+        if let Some(matchups) = self.matchups.as_mut() {
+            matchups.clear();
+        }
+        self.last_players = Default::default();
+        self.live_players = Default::default();
+        self.generation_history = Default::default();
+        self.players = self.initial_players.clone();
+    }
+
+    /// Get the scores of the last played generation (empty before the first generation).
+    ///
+    /// The scores are in the order of the population before the genetic strategy is applied, not
+    /// in the order of [`Self::population`].
+    pub fn scores(&self) -> &[T] {
+        &self.scores
+    }
+
+    /// Get the number of matches each player played in the last generation (in the order of
+    /// [`Self::scores`]).
+    pub fn matches_played(&self) -> &[usize] {
+        &self.matches_played
+    }
+
+    /// Get the number of rounds each player played in the last generation (in the order of
+    /// [`Self::scores`]).
+    ///
+    /// Dividing the scores by these keeps them comparable when the rounds vary (see
+    /// [`RoundSchedule`]).
+    pub fn rounds_played(&self) -> &[usize] {
+        &self.rounds_played
+    }
+
+    /// Get the type (constructor index) of each player of the current population.
+    pub fn population(&self) -> &[usize] {
+        &self.players
+    }
+
+    /// Get the number of players of each type (indexed by constructor) in the current population.
+    pub fn census(&self) -> Vec<usize> {
+        let mut census = vec![0; self.player_constructors.len()];
+        for &i in self.players.iter() {
+            census[i] += 1;
+        }
+        census
+    }
+
+    /// Get the number of the player types (constructors).
+    pub fn constructor_count(&self) -> usize {
+        self.player_constructors.len()
+    }
+
+    /// Get the most rounds played by every two players in each generation (the rounds of a
+    /// fixed schedule).
+    pub fn rounds(&self) -> usize {
+        self.round_schedule.max_rounds()
+    }
+
+    /// Set the rounds of the matches of the next generations (see [`RoundSchedule::Fixed`]).
+    ///
+    /// Returns Err if the rounds is zero (leaving the rounds unchanged).
+    pub fn set_rounds(&mut self, rounds: usize) -> Result<(), ArenaError> {
+        self.set_round_schedule(RoundSchedule::Fixed(rounds))
+    }
+
+    /// Get how many rounds each two players play.
+    pub fn round_schedule(&self) -> &RoundSchedule {
+        &self.round_schedule
+    }
+
+    /// Set how many rounds each two players play in the next generations.
+    ///
+    /// Returns Err if the schedule is invalid (see [`RoundSchedule::validate`]), leaving the
+    /// schedule unchanged.
+    pub fn set_round_schedule(&mut self, round_schedule: RoundSchedule) -> Result<(), ArenaError> {
+        round_schedule.validate()?;
+        self.round_schedule = round_schedule;
+        Ok(())
+    }
+
+    /// Get the strategy applied to the population after each generation.
+    pub fn strategy(&self) -> &GeneticStrategy {
+        &self.strategy
+    }
+
+    /// Set the strategy applied to the population after the next generations.
+    ///
+    /// Returns Err if the strategy does not fit the current population (leaving the strategy
+    /// unchanged).
+    pub fn set_strategy(&mut self, strategy: GeneticStrategy) -> Result<(), ArenaError> {
+        strategy.validate(self.players.len())?;
+        self.strategy = strategy;
+        Ok(())
+    }
+
+    /// Get how the players are paired in each generation.
+    pub fn pairing_mode(&self) -> PairingMode {
+        self.pairing_mode
+    }
+
+    /// Set how the players are paired in the next generations.
+    pub fn set_pairing_mode(&mut self, pairing_mode: PairingMode) {
+        self.pairing_mode = pairing_mode;
+    }
+
+    /// Get how long the players remember their games.
+    pub fn memory_policy(&self) -> MemoryPolicy {
+        self.memory_policy
+    }
+
+    /// Set how long the players remember their games from the next generation on.
+    ///
+    /// The players start the next generation with no memory either way.
+    pub fn set_memory_policy(&mut self, memory_policy: MemoryPolicy) {
+        self.memory_policy = memory_policy;
+        self.live_players = Default::default();
+    }
+
+    /// Get the sum of the scores of each type of player in the last played generation.
+    ///
+    /// Types with no players in the last generation are not present.
+    pub fn scores_by_type(&self) -> HashMap<usize, T> {
+        let mut scores = HashMap::new();
+        for (&i, score) in self.last_players.iter().zip(self.scores.iter()) {
+            *scores.entry(i).or_insert_with(T::default) += score.clone();
+        }
+        scores
+    }
+
+    /// Get the names of the player types (indexed by their IDs as in [`Self::scores_by_type`]).
+    pub fn constructor_names(&self) -> Vec<Cow<'_, str>> {
+        self.constructor_names
+            .iter()
+            .map(|name| name.as_str().into())
+            .collect()
+    }
+
+    /// Get the name of the player type in the given slot of the current population.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is out of the population.
+    pub fn player_name(&self, slot: usize) -> Cow<'_, str> {
+        self.constructor_names[self.players[slot]].as_str().into()
+    }
+
+    /// Set the observer to notify of the pairings and generations played from now on.
+    pub fn set_observer(&mut self, observer: Box<P::Observer>) {
+        self.observer = Some(observer);
+    }
+
+    /// Reseed the players and the machine of every match for reproducible generations.
+    ///
+    /// Each generation and each match gets a different seed derived from this seed. Generation
+    /// `g` is seeded with `seed ^ (g * 0x9E3779B97F4A7C15)` and its match of slots `i` and `j`
+    /// (in a population of `n`) with `3 * (i * n + j)` added to it (plus `3 * round * n * n` for
+    /// each pairing round of [`PairingMode::RandomPairs`]), which the first player, the second
+    /// player and the machine are reseeded with consecutively. The seeds only depend on
+    /// the slots hence the order of playing (e.g. in parallel) does not change the results.
+    /// Without a seed, the randomized types roll from the entropy of the thread generators.
+    #[cfg(feature = "rand")]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Flip each consent of every match by the given chance (`0..=1`) on top of the machine.
+    ///
+    /// The flips are seeded by [`Self::with_seed`] if given.
+    #[cfg(feature = "rand")]
+    pub fn with_noise(mut self, chance: f32) -> Self {
+        self.noise = chance;
+        self
+    }
+
+    /// Get the chance of flipping each consent in the matches (0.0 if noiseless).
+    pub fn noise(&self) -> f32 {
+        self.noise
+    }
+
+    /// Record the types and the scores of every match played from now on (see
+    /// [`Self::matchup_history`]).
+    pub fn with_matchup_recording(mut self) -> Self {
+        self.matchups = Some(vec![]);
+        self
+    }
+
+    /// Get the types and the scores of both sides (`(type_i, type_j, score_i, score_j)`) of the
+    /// matches played since the recording started (empty if not recorded).
+    ///
+    /// This is the input of [`crate::statistics::pairwise_win_rates`].
+    pub fn matchup_history(&self) -> &[(usize, usize, T, T)] {
+        self.matchups.as_deref().unwrap_or_default()
+    }
+
+    /// Get the seed of the next generation (if seeded).
+    fn generation_seed(&self) -> Option<u64> {
+        let generation = self.generation_history.len() as u64;
+        self.seed
+            .map(|seed| seed ^ generation.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+
+    /// Get the slots of the players of each match in this generation (by [`PairingMode`]) and
+    /// the index to derive the seed of the match from.
+    fn pairs(&self) -> Vec<(usize, usize, usize)> {
+        let n = self.players.len();
+        match self.pairing_mode {
+            PairingMode::RoundRobin => (0..n)
+                .flat_map(|i| ((i + 1)..n).map(move |j| (i, j, i * n + j)))
+                .collect(),
+            #[cfg(feature = "rand")]
+            PairingMode::RandomPairs { rounds_of_pairing } => {
+                use rand::seq::SliceRandom;
+
+                let mut rng = self.generation_rng();
+                let mut slots: Vec<usize> = (0..n).collect();
+                (0..rounds_of_pairing)
+                    .flat_map(|round| {
+                        slots.shuffle(&mut rng);
+                        slots
+                            .chunks_exact(2)
+                            .map(|pair| (pair[0], pair[1], (round * n + pair[0]) * n + pair[1]))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "rand")]
+            PairingMode::SampleOpponents { k } => {
+                let mut rng = self.generation_rng();
+                let k = k.min(n.saturating_sub(1));
+                // ordered pairs so the mutual picks are played once
+                let mut pairs = std::collections::BTreeSet::new();
+                for i in 0..n {
+                    for pick in rand::seq::index::sample(&mut rng, n - 1, k) {
+                        // skip the player itself
+                        let j = if pick < i { pick } else { pick + 1 };
+                        pairs.insert((i.min(j), i.max(j)));
+                    }
+                }
+                pairs.into_iter().map(|(i, j)| (i, j, i * n + j)).collect()
+            }
+        }
+    }
+
+    /// Same as `pairs` but with the rounds each pair plays (see [`RoundSchedule`]).
+    fn scheduled_pairs(&self) -> Vec<(usize, usize, usize, PairingRounds)> {
+        let generation_seed = self.generation_seed();
+        let announced = self.round_schedule.announces_rounds();
+        self.pairs()
+            .into_iter()
+            .enumerate()
+            .map(|(pairing, (i, j, index))| {
+                let seed = schedule_seed(match_seed(generation_seed, index));
+                let count = self.round_schedule.rounds_for(pairing, seed);
+                (i, j, index, PairingRounds { count, announced })
+            })
+            .collect()
+    }
+
+    /// Get the random generator of the pairings of the next generation (seeded if the arena is).
+    #[cfg(feature = "rand")]
+    fn generation_rng(&self) -> rand::rngs::StdRng {
+        use rand::SeedableRng;
+
+        match self.generation_seed() {
+            Some(seed) => rand::rngs::StdRng::seed_from_u64(seed),
+            None => rand::rngs::StdRng::from_entropy(),
+        }
+    }
+
+    /// Play the paired players of the population against each other (IDs in population and
+    /// scores).
+    fn play_pairs(&mut self) -> Vec<PairResult<T>> {
+        let generation_seed = self.generation_seed();
+        let record_rounds = self.observer.is_some();
+        let mut results = vec![];
+        for (i, j, index, rounds) in self.scheduled_pairs() {
+            let p1 = self.player_constructors[self.players[i]].build();
+            let p2 = self.player_constructors[self.players[j]].build();
+
+            let seed = match_seed(generation_seed, index);
+            let (scores, outcomes, _) = play_match(
+                &mut self.machine,
+                p1,
+                p2,
+                rounds,
+                seed,
+                self.noise,
+                record_rounds,
+            );
+            results.push((i, j, scores, outcomes, rounds.count));
+        }
+        results
+    }
+
+    /// Same as `play_pairs` but each slot plays all its pairings with its live player (see
+    /// [`MemoryPolicy`]).
+    fn play_pairs_with_memory(&mut self) -> Vec<PairResult<T>> {
+        if self.live_players.len() != self.players.len() {
+            self.live_players = self
+                .players
+                .iter()
+                .map(|&t| Some(self.player_constructors[t].build()))
+                .collect();
+        }
+        let generation_seed = self.generation_seed();
+        let record_rounds = self.observer.is_some();
+        let mut results = vec![];
+        for (i, j, index, rounds) in self.scheduled_pairs() {
+            let missing = "a live player is taken only while playing";
+            let p1 = self.live_players[i].take().expect(missing);
+            let p2 = self.live_players[j].take().expect(missing);
+
+            let seed = match_seed(generation_seed, index);
+            let (scores, outcomes, (p1, p2)) = play_match(
+                &mut self.machine,
+                p1,
+                p2,
+                rounds,
+                seed,
+                self.noise,
+                record_rounds,
+            );
+            self.live_players[i] = Some(p1);
+            self.live_players[j] = Some(p2);
+            results.push((i, j, scores, outcomes, rounds.count));
+        }
+        results
+    }
+
+    /// Whether every match of this generation is decided by the types of its players alone.
+    ///
+    /// That is when the machine and the factories of every present type are deterministic (see
+    /// [`PlayerFactory::is_deterministic`]), the players forget each pairing, the rounds are
+    /// fixed, there is no noise and no observer wants the rounds.
+    fn plays_deterministically(&self) -> bool {
+        let mut types = self.players.clone();
+        types.sort_unstable();
+        types.dedup();
+        self.memory_policy == MemoryPolicy::ForgetEachPairing
+            && matches!(self.round_schedule, RoundSchedule::Fixed(_))
+            && self.noise == 0.0
+            && self.observer.is_none()
+            && self.machine.is_deterministic()
+            && types
+                .into_iter()
+                .all(|t| self.player_constructors[t].is_deterministic())
+    }
+
+    /// Same as `play_pairs` but each ordered pair of types plays once and its scores are reused
+    /// for the other pairs of the same types (only valid if [`Self::plays_deterministically`]).
+    ///
+    /// The types are kept in order since the game matrix may favor one side.
+    fn play_pairs_deduplicated(&mut self) -> Vec<PairResult<T>> {
+        let rounds = self.round_schedule.max_rounds();
+        let mut scores_by_types: HashMap<(usize, usize), (T, T)> = HashMap::new();
+        let mut results = vec![];
+        for (i, j, _) in self.pairs() {
+            let types = (self.players[i], self.players[j]);
+            let scores = match scores_by_types.get(&types) {
+                Some(scores) => scores.clone(),
+                None => {
+                    let p1 = self.player_constructors[types.0].build();
+                    let p2 = self.player_constructors[types.1].build();
+                    let (scores, _, _) =
+                        play_match(&mut self.machine, p1, p2, rounds.into(), None, 0.0, false);
+                    scores_by_types.insert(types, scores.clone());
+                    scores
+                }
+            };
+            results.push((i, j, scores, vec![], rounds));
+        }
+        results
+    }
+
+    /// Get the total score of the newcomer after playing against every player of the population.
+    ///
+    /// The population is not changed by this probe.
+    pub fn play_all_vs_one(&mut self, newcomer: Box<dyn PlayerTrait<T>>) -> T {
+        let generation_seed = self.generation_seed();
+        let mut score = T::default();
+        let announced = self.round_schedule.announces_rounds();
+        for i in 0..self.players.len() {
+            let opponent = self.player_constructors[self.players[i]].build();
+            let seed = match_seed(generation_seed, i);
+            let count = self.round_schedule.rounds_for(i, schedule_seed(seed));
+            let (scores, _, _) = play_match(
+                &mut self.machine,
+                fresh_clone(&*newcomer),
+                opponent,
+                PairingRounds { count, announced },
+                seed,
+                self.noise,
+                false,
+            );
+            score += scores.0;
+        }
+        score
+    }
+
+    /// Get the summary of each generation played so far.
+    pub fn per_generation_stats(&self) -> &[GenerationStats<T>] {
+        &self.generation_history
+    }
+
+    /// Get the normalized Herfindahl-Hirschman Index of the population.
+    ///
+    /// Ranges from 0.0 (one type only or empty) to near 1.0 (many types evenly present).
+    pub fn population_diversity(&self) -> f64 {
+        diversity(&self.players)
+    }
+}
+
+impl<T, M, P> Arena<T, M, P>
+where
+    T: Clone + Default + AddAssign<T> + Ord,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+    Box<P>: PlayerTrait<T>,
+{
+    /// Play the given number of generations and record a snapshot after each one.
+    pub fn run_for_generations(&mut self, n: usize) -> Vec<GenerationSnapshot<T>> {
+        (0..n)
+            .map(|generation_index| {
+                self.play();
+                GenerationSnapshot {
+                    generation_index,
+                    players: self.last_players.clone(),
+                    population: self.players.clone(),
+                    scores: self.scores.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Play the given number of generations and record the census and the scores of each.
+    ///
+    /// Each generation is played exactly like [`MatchTrait::play`].
+    pub fn play_generations(&mut self, generations: usize) -> SimulationHistory<T> {
+        let mut history = SimulationHistory {
+            initial_census: self.census(),
+            censuses: Vec::with_capacity(generations),
+            scores: Vec::with_capacity(generations),
+        };
+        for _ in 0..generations {
+            self.play();
+            history.censuses.push(self.census());
+            history.scores.push(self.scores.clone());
+        }
+        history
+    }
+
+    /// Play until the census is unchanged (or alternates between two states) for `patience`
+    /// generations in a row or `max_generations` are played.
+    ///
+    /// Unlike [`Self::run_until_convergence`], only the number of players of each type matters and
+    /// not the order of the population.
+    pub fn play_until_stable(&mut self, max_generations: usize, patience: usize) -> StableOutcome {
+        let mut censuses = vec![self.census()];
+        for generations in 0..=max_generations {
+            match census_stability(&censuses, patience) {
+                Some(Stability::Stable) => {
+                    return StableOutcome::Stable {
+                        generations,
+                        census: censuses.pop().unwrap_or_default(),
+                    }
+                }
+                Some(Stability::Cycle) => {
+                    return StableOutcome::Cycle {
+                        generations,
+                        census: censuses.pop().unwrap_or_default(),
+                        other_census: censuses.pop().unwrap_or_default(),
+                    }
+                }
+                None if generations < max_generations => {
+                    self.play();
+                    censuses.push(self.census());
+                }
+                None => {}
+            }
+        }
+
+        StableOutcome::MaxGenerationsReached {
+            generations: max_generations,
+            census: censuses.pop().unwrap_or_default(),
+        }
+    }
+
+    /// Play a generation and get its report by type.
+    ///
+    /// The scores are converted with the given function to calculate the averages (e.g.
+    /// `|score| score as f64` for `isize`).
+    pub fn play_reported(&mut self, to_f64: impl Fn(&T) -> f64) -> GenerationReport<T> {
+        let census_before = self.census();
+        self.play();
+
+        let mut total_scores = vec![T::default(); self.player_constructors.len()];
+        let mut best: Option<(usize, T)> = None;
+        let mut worst: Option<(usize, T)> = None;
+        for (&i, score) in self.last_players.iter().zip(self.scores.iter()) {
+            total_scores[i] += score.clone();
+            if best.as_ref().map_or(true, |(_, best)| score > best) {
+                best = Some((i, score.clone()));
+            }
+            if worst.as_ref().map_or(true, |(_, worst)| score < worst) {
+                worst = Some((i, score.clone()));
+            }
+        }
+        let average_scores = total_scores
+            .iter()
+            .zip(census_before.iter())
+            .map(|(total, &count)| {
+                if count == 0 {
+                    0.0
+                } else {
+                    to_f64(total) / count as f64
+                }
+            })
+            .collect();
+
+        GenerationReport {
+            census_after: self.census(),
+            census_before,
+            total_scores,
+            average_scores,
+            best,
+            worst,
+            pairings: self.matches_played.iter().sum::<usize>() / 2,
+            rounds: self.rounds_played.iter().sum::<usize>() / 2,
+            noise: self.noise,
+        }
+    }
+
+    /// Play until the population is unchanged for `stability_window` generations in a row.
+    ///
+    /// Returns the index of the generation in which the convergence is detected and the snapshots
+    /// of all the played generations or Err if not converged in `max_generations`.
+    pub fn run_until_convergence(
+        &mut self,
+        max_generations: usize,
+        stability_window: usize,
+    ) -> Result<(usize, Vec<GenerationSnapshot<T>>), ConvergenceError> {
+        let sorted = |population: &[usize]| {
+            let mut population = population.to_vec();
+            population.sort_unstable();
+            population
+        };
+
+        let mut snapshots = vec![];
+        if stability_window == 0 {
+            return Ok((0, snapshots));
+        }
+
+        let mut last_population = sorted(&self.players);
+        let mut stable_generations = 0;
+        for generation_index in 0..max_generations {
+            self.play();
+            let population = sorted(&self.players);
+            if population == last_population {
+                stable_generations += 1;
+            } else {
+                stable_generations = 0;
+                last_population = population;
+            }
+
+            snapshots.push(GenerationSnapshot {
+                generation_index,
+                players: self.last_players.clone(),
+                population: self.players.clone(),
+                scores: self.scores.clone(),
+            });
+
+            if stable_generations >= stability_window {
+                return Ok((generation_index, snapshots));
+            }
+        }
+
+        Err(ConvergenceError::MaxGenerationsReached)
+    }
+}
+
+/// A helper to construct an [`Arena`] step by step (validated on [`Self::build`]).
+pub struct ArenaBuilder<T = isize, M = Machine<T>, P = dyn PlayerTrait<T>>
+where
+    P: ?Sized + ArenaPlayer<T>,
+{
+    machine: Option<M>,
+    player_constructors: Vec<Box<P::Factory>>,
+    players: Vec<usize>,
+    rounds: usize,
+    strategy: GeneticStrategy,
+    pairing_mode: PairingMode,
+    memory_policy: MemoryPolicy,
+    round_schedule: Option<RoundSchedule>,
+}
+
+impl<T, M, P: ?Sized + ArenaPlayer<T>> Default for ArenaBuilder<T, M, P> {
+    fn default() -> Self {
+        Self {
+            machine: None,
+            player_constructors: vec![],
+            players: vec![],
+            rounds: 0,
+            strategy: GeneticStrategy::Keep,
+            pairing_mode: PairingMode::RoundRobin,
+            memory_policy: MemoryPolicy::ForgetEachPairing,
+            round_schedule: None,
+        }
+    }
+}
+
+impl<T, M, P> ArenaBuilder<T, M, P>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+    Box<P>: PlayerTrait<T>,
+{
+    /// Set the machine used for each 1v1 competition.
+    pub fn machine(mut self, machine: M) -> Self {
+        self.machine = Some(machine);
+        self
+    }
+
+    /// Add a player type (its ID is the number of types added before it).
+    pub fn add_player_type(mut self, player: Box<P>) -> Self
+    where
+        T: 'static,
+    {
+        self.player_constructors.push(P::prototype_factory(player));
+        self
+    }
+
+    /// Add a player type with the given number of its players to the first generation.
+    ///
+    /// Same as [`Self::add_player_type`] but no bookkeeping of the IDs is needed for
+    /// [`Self::initial_population`].
+    pub fn add_type(mut self, player: Box<P>, count: usize) -> Self
+    where
+        T: 'static,
+    {
+        let id = self.player_constructors.len();
+        self.players.extend(std::iter::repeat(id).take(count));
+        self.add_player_type(player)
+    }
+
+    /// Add a player type built by the factory (see [`Self::add_player_type`]).
+    pub fn add_player_factory(mut self, factory: Box<P::Factory>) -> Self {
+        self.player_constructors.push(factory);
+        self
+    }
+
+    /// Set the first generation of players (IDs of the added player types).
+    pub fn initial_population(mut self, players: Vec<usize>) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Set the rounds per play for each two opponents.
+    pub fn rounds_per_match(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Set how to move from one generation to another ([`GeneticStrategy::Keep`] by default).
+    pub fn strategy(mut self, strategy: GeneticStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set how the players are paired ([`PairingMode::RoundRobin`] by default).
+    pub fn pairing_mode(mut self, pairing_mode: PairingMode) -> Self {
+        self.pairing_mode = pairing_mode;
+        self
+    }
+
+    /// Set how many rounds each two players play instead of [`Self::rounds_per_match`].
+    pub fn round_schedule(mut self, round_schedule: RoundSchedule) -> Self {
+        self.round_schedule = Some(round_schedule);
+        self
+    }
+
+    /// Set how long the players remember their games ([`MemoryPolicy::ForgetEachPairing`] by
+    /// default).
+    pub fn memory_policy(mut self, memory_policy: MemoryPolicy) -> Self {
+        self.memory_policy = memory_policy;
+        self
+    }
+
+    /// Returns the arena or Err if any of the given parameters is missing or invalid.
+    pub fn build(self) -> Result<Arena<T, M, P>, ArenaError> {
+        let machine = self.machine.ok_or(ArenaError::MissingMachine)?;
+        let rounds = match &self.round_schedule {
+            Some(round_schedule) => round_schedule.max_rounds(),
+            None => self.rounds,
+        };
+        let mut arena = Arena::from_factories(
+            machine,
+            self.player_constructors,
+            self.players,
+            rounds,
+            self.strategy,
+        )?;
+        if let Some(round_schedule) = self.round_schedule {
+            arena.set_round_schedule(round_schedule)?;
+        }
+        arena.pairing_mode = self.pairing_mode;
+        arena.memory_policy = self.memory_policy;
+        Ok(arena)
+    }
+}
+
+/// The serialized state of an [`Arena`] (see [`Arena::export_state`]).
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ArenaState<T, M> {
+    machine: M,
+    roster: Vec<BuiltinPlayer>,
+    players: Vec<usize>,
+    initial_players: Vec<usize>,
+    last_players: Vec<usize>,
+    generation_history: Vec<GenerationStats<T>>,
+    scores: Vec<T>,
+    matches_played: Vec<usize>,
+    rounds_played: Vec<usize>,
+    round_schedule: RoundSchedule,
+    strategy: GeneticStrategy,
+    pairing_mode: PairingMode,
+    memory_policy: MemoryPolicy,
+    seed: Option<u64>,
+    noise: f32,
+}
+
+#[cfg(feature = "serde")]
+impl<M> Arena<isize, M>
+where
+    M: MachineTrait<isize> + Clone + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Get the state of the arena as JSON to resume it later (see [`Self::import_state`]).
+    ///
+    /// Returns Err if the arena is not built from a roster (see [`Self::from_roster`]). The
+    /// observer and the memories of the players (see [`MemoryPolicy`]) are not a part of the
+    /// state.
+    pub fn export_state(&self) -> Result<String, ArenaError> {
+        let roster = self.roster.clone().ok_or(ArenaError::MissingRoster)?;
+        Ok(serde_json::to_string(&ArenaState {
+            machine: self.machine.clone(),
+            roster,
+            players: self.players.clone(),
+            initial_players: self.initial_players.clone(),
+            last_players: self.last_players.clone(),
+            generation_history: self.generation_history.clone(),
+            scores: self.scores.clone(),
+            matches_played: self.matches_played.clone(),
+            rounds_played: self.rounds_played.clone(),
+            round_schedule: self.round_schedule.clone(),
+            strategy: self.strategy,
+            pairing_mode: self.pairing_mode,
+            memory_policy: self.memory_policy,
+            seed: self.seed,
+            noise: self.noise,
+        })?)
+    }
+
+    /// Restore an arena from its state (see [`Self::export_state`]).
+    ///
+    /// Returns Err if the JSON is malformed or the state is not a valid arena.
+    pub fn import_state(json: &str) -> Result<Self, ArenaError> {
+        let state: ArenaState<isize, M> = serde_json::from_str(json)?;
+        let mut arena = Self::from_roster(
+            state.machine,
+            state.roster,
+            state.players,
+            state.round_schedule.max_rounds(),
+            state.strategy,
+        )?;
+        arena.set_round_schedule(state.round_schedule)?;
+        arena.initial_players = state.initial_players;
+        arena.last_players = state.last_players;
+        arena.generation_history = state.generation_history;
+        arena.scores = state.scores;
+        arena.matches_played = state.matches_played;
+        arena.rounds_played = state.rounds_played;
+        arena.pairing_mode = state.pairing_mode;
+        arena.memory_policy = state.memory_policy;
+        arena.seed = state.seed;
+        arena.noise = state.noise;
+        Ok(arena)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, M, P> Arena<T, M, P>
+where
+    T: Clone + Default + AddAssign<T> + Ord + Send,
+    M: MachineTrait<T> + Clone + Sync,
+    P: ?Sized + ArenaPlayer<T>,
+    P::Factory: Sync,
+    Box<P>: PlayerTrait<T>,
+{
+    /// Same as [`MatchTrait::play`] but the pairs play in parallel (requires "parallel").
+    ///
+    /// Each pair plays on its own copy of the machine with players built in its thread. The
+    /// scores are identical to the sequential play. Only the players forgetting each pairing play
+    /// in parallel (see [`MemoryPolicy`]).
+    pub fn play_parallel(&mut self) -> GenerationStats<T> {
+        self.play_generation(|arena| arena.play_pairs_parallel())
+    }
+
+    /// Same as `play_pairs` but each pair plays in parallel on its own copy of the machine.
+    ///
+    /// The results are collected in the order of the pairs so summing them gives the same scores
+    /// as the sequential play (even for floating point scores whose sums depend on the order).
+    fn play_pairs_parallel(&self) -> Vec<PairResult<T>> {
+        use rayon::prelude::*;
+
+        let (constructors, players, machine, noise) = (
+            &self.player_constructors,
+            &self.players,
+            &self.machine,
+            self.noise,
+        );
+        let generation_seed = self.generation_seed();
+        let record_rounds = self.observer.is_some();
+        self.scheduled_pairs()
+            .into_par_iter()
+            .map(|(i, j, index, rounds)| {
+                let p1 = constructors[players[i]].build();
+                let p2 = constructors[players[j]].build();
+                let seed = match_seed(generation_seed, index);
+                let (scores, outcomes, _) = play_match(
+                    &mut machine.clone(),
+                    p1,
+                    p2,
+                    rounds,
+                    seed,
+                    noise,
+                    record_rounds,
+                );
+                (i, j, scores, outcomes, rounds.count)
+            })
+            .collect()
+    }
+}
+
+impl<T, M, P> MatchTrait<T> for Arena<T, M, P>
+where
+    T: Clone + Default + AddAssign<T> + Ord,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+    Box<P>: PlayerTrait<T>,
+{
+    type Outcome = GenerationStats<T>;
+
+    /// Play a generation and get its summary.
+    fn play(&mut self) -> GenerationStats<T> {
+        self.play_generation(Self::play_pairs)
+    }
+
+    /// Get the scores of the last played generation (by slot as in [`Self::player_name`]).
+    fn standings(&self) -> Vec<(usize, T)> {
+        self.scores.iter().cloned().enumerate().collect()
+    }
+
+    /// See [`Arena::reset`].
+    fn reset(&mut self) {
+        Arena::reset(self)
+    }
+}
+
+impl<T, M, P> Arena<T, M, P>
+where
+    T: Clone + Default + AddAssign<T> + Ord,
+    M: MachineTrait<T>,
+    P: ?Sized + ArenaPlayer<T>,
+    Box<P>: PlayerTrait<T>,
+{
+    /// Play a generation with the given play of the pairs (if the players forget each pairing
+    /// and the generation does not play deterministically) and get its summary.
+    fn play_generation(
+        &mut self,
+        play_pairs: impl FnOnce(&mut Self) -> Vec<PairResult<T>>,
+    ) -> GenerationStats<T> {
+        // reset scores.
+        self.scores = vec![Default::default(); self.players.len()];
+        self.matches_played = vec![0; self.players.len()];
+        self.rounds_played = vec![0; self.players.len()];
+
+        let results = if self.memory_policy != MemoryPolicy::ForgetEachPairing {
+            self.play_pairs_with_memory()
+        } else if self.plays_deterministically() {
+            self.play_pairs_deduplicated()
+        } else {
+            play_pairs(self)
+        };
+
+        // memorize the results (notifying the observer in the order of the pairs)
+        for (i, j, ovo_results, outcomes, rounds) in results {
+            if let Some(observer) = self.observer.as_mut() {
+                observer.on_pairing_start(self.players[i], self.players[j]);
+                for outcome in &outcomes {
+                    observer.on_round(outcome);
+                }
+                observer.on_pairing_end(&ovo_results);
+            }
+            if let Some(matchups) = self.matchups.as_mut() {
+                matchups.push((
+                    self.players[i],
+                    self.players[j],
+                    ovo_results.0.clone(),
+                    ovo_results.1.clone(),
+                ));
+            }
+            self.scores[i] += ovo_results.0;
+            self.scores[j] += ovo_results.1;
+            self.matches_played[i] += 1;
+            self.matches_played[j] += 1;
+            self.rounds_played[i] += rounds;
+            self.rounds_played[j] += rounds;
+        }
+
+        // The slots of the players (best at the end of the array).
+        // TODO add other multiplication strategies for the next generation.
+        let sorted_slots = {
+            let mut t = self
+                .scores
+                .clone()
+                .into_iter()
+                .zip(self.matches_played.iter().copied())
+                .enumerate()
+                .collect::<Vec<(usize, (T, usize))>>();
+            if self.pairing_mode.ranks_per_match() {
+                t.sort_by(|(_, (a, a_matches)), (_, (b, b_matches))| {
+                    cmp_per_match(a, *a_matches, b, *b_matches)
+                });
+            } else {
+                t.sort_by_key(|(_, (v, _))| v.clone());
+            }
+            t.into_iter().map(|(slot, _)| slot).collect::<Vec<usize>>()
+        };
+
+        // the strategy only moves and repeats the slots so the survivors can be told apart
+        let next_slots = self
+            .strategy
+            .apply_to_vec(sorted_slots)
+            .expect("the genetic strategy is validated against a population never shrinking");
+        let next_players = next_slots.iter().map(|&slot| self.players[slot]).collect();
+        self.live_players = match self.memory_policy {
+            MemoryPolicy::PersistAcrossGenerations => {
+                let mut survivors = std::mem::take(&mut self.live_players);
+                next_slots
+                    .iter()
+                    .map(|&slot| match survivors[slot].take() {
+                        // the first of the repeated slots keeps the memory
+                        Some(player) => Some(player),
+                        None => Some(self.player_constructors[self.players[slot]].build()),
+                    })
+                    .collect()
+            }
+            _ => Default::default(),
+        };
+        self.last_players = std::mem::replace(&mut self.players, next_players);
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_generation_end(&self.last_players, &self.scores);
+        }
+        let stats = GenerationStats::new(&self.last_players, &self.scores);
+        self.generation_history.push(stats.clone());
+        stats
+    }
+}
+
+/// The cells around a cell of a [`GridArena`] which it plays against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    /// The 4 orthogonally adjacent cells.
+    #[default]
+    VonNeumann,
+    /// The 8 orthogonally and diagonally adjacent cells.
+    Moore,
+}
+
+impl Neighborhood {
+    /// Get the offsets (row, column) of the neighbors.
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Self::VonNeumann => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Self::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// A grid where each cell plays against its neighbors and then adopts the type of the best one.
+pub struct GridArena<T: Default + Clone = isize, M = Machine<T>>
+where
+    T: Clone + Default,
+    M: MachineTrait<T>,
+{
+    /// The rule of the base match for each 1v1 competition.
+    machine: M,
+    /// Builds the players of each type present in the game.
+    player_constructors: Vec<Box<dyn PlayerFactory<T>>>,
+    /// The number of cells in each row.
+    width: usize,
+    /// The type of each cell (the ID of `player_constructors`) row by row.
+    cells: Vec<usize>,
+    /// The cells at construction (to reset to).
+    initial_cells: Vec<usize>,
+    /// The score of each cell in the last played generation.
+    scores: Vec<T>,
+    /// Rounds per play for each two neighbors.
+    rounds: usize,
+    /// Which cells are neighbors.
+    neighborhood: Neighborhood,
+    /// Whether the edges wrap around (the neighbors of the edges are on the opposite edges).
+    toroidal: bool,
+}
+
+impl<T, M> GridArena<T, M>
+where
+    T: Clone + Default + AddAssign<T>,
+    M: MachineTrait<T>,
+{
+    /// Returns the grid or Err if any of the inputs is empty (or zero rounds), the cells are not
+    /// in `0..player_constructors.len()` or they do not fill rows of the given width.
+    ///
+    /// The cells are given row by row. The given players are prototypes cloned (and forgotten)
+    /// for each match.
+    pub fn new(
+        machine: M,
+        player_constructors: Vec<Box<dyn PlayerTrait<T>>>,
+        width: usize,
+        cells: Vec<usize>,
+        rounds: usize,
+    ) -> Result<Self, ArenaError>
+    where
+        T: 'static,
+    {
+        let factories = player_constructors
+            .into_iter()
+            .map(|i| Box::new(i) as Box<dyn PlayerFactory<T>>)
+            .collect();
+        Self::with_factories(machine, factories, width, cells, rounds)
+    }
+
+    /// Same as [`Self::new`] but builds the players of each match with the given factories.
+    pub fn with_factories(
+        machine: M,
+        factories: Vec<Box<dyn PlayerFactory<T>>>,
+        width: usize,
+        cells: Vec<usize>,
+        rounds: usize,
+    ) -> Result<Self, ArenaError> {
+        if cells.is_empty() {
+            return Err(ArenaError::EmptyPlayers);
+        }
+        if factories.is_empty() {
+            return Err(ArenaError::EmptyConstructors);
+        }
+        if rounds == 0 {
+            return Err(ArenaError::RoundCountZero);
+        }
+        if width == 0 || cells.len() % width != 0 {
+            return Err(ArenaError::InvalidGridShape);
+        }
+        if cells.iter().any(|&i| i >= factories.len()) {
+            return Err(ArenaError::UnknownPlayer);
+        }
+
+        Ok(Self {
+            machine,
+            player_constructors: factories,
+            width,
+            initial_cells: cells.clone(),
+            cells,
+            scores: Default::default(),
+            rounds,
+            neighborhood: Default::default(),
+            toroidal: false,
+        })
+    }
+
+    /// Set which cells are neighbors.
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Set whether the edges wrap around (i.e. the grid is a torus).
+    pub fn with_toroidal(mut self, toroidal: bool) -> Self {
+        self.toroidal = toroidal;
+        self
+    }
+
+    /// Get the number of cells in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the number of rows.
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    /// Get the type of each cell (the ID of the player constructors) row by row.
+    pub fn grid(&self) -> Vec<&[usize]> {
+        self.cells.chunks(self.width).collect()
+    }
+
+    /// Get the score of each cell in the last played generation row by row (empty before the
+    /// first generation).
+    pub fn scores(&self) -> Vec<&[T]> {
+        self.scores.chunks(self.width).collect()
+    }
+
+    /// Restore the cells to the state at construction.
+    pub fn reset(&mut self) {
+        self.scores = Default::default();
+        self.cells = self.initial_cells.clone();
+    }
+
+    /// Get the distinct neighbors of the cell (excluding the cell itself).
+    fn neighbors(&self, cell: usize) -> Vec<usize> {
+        let (width, height) = (self.width as isize, self.height() as isize);
+        let (row, column) = ((cell / self.width) as isize, (cell % self.width) as isize);
+        let mut neighbors = vec![];
+        for &(dr, dc) in self.neighborhood.offsets() {
+            let (mut r, mut c) = (row + dr, column + dc);
+            if self.toroidal {
+                r = r.rem_euclid(height);
+                c = c.rem_euclid(width);
+            } else if r < 0 || r >= height || c < 0 || c >= width {
+                continue;
+            }
+            let neighbor = (r * width + c) as usize;
+            // small tori wrap onto the cell itself or the same neighbor twice
+            if neighbor != cell && !neighbors.contains(&neighbor) {
+                neighbors.push(neighbor);
+            }
+        }
+        neighbors
+    }
+}
+
+impl<T, M> MatchTrait<T> for GridArena<T, M>
+where
+    T: Clone + Default + AddAssign<T> + Ord,
+    M: MachineTrait<T>,
+{
+    type Outcome = GenerationStats<T>;
+
+    /// Play a generation and get its summary.
+    ///
+    /// Every two neighbors play a match once and each cell adopts the type of its best scoring
+    /// neighbor (keeping its own type unless a neighbor scores strictly more).
+    fn play(&mut self) -> GenerationStats<T> {
+        self.scores = vec![Default::default(); self.cells.len()];
+        let neighbors: Vec<Vec<usize>> = (0..self.cells.len())
+            .map(|cell| self.neighbors(cell))
+            .collect();
+
+        for (i, cell_neighbors) in neighbors.iter().enumerate() {
+            for &j in cell_neighbors.iter().filter(|&&j| j > i) {
+                let p1 = self.player_constructors[self.cells[i]].build();
+                let p2 = self.player_constructors[self.cells[j]].build();
+                let (scores, _, _) = play_match(
+                    &mut self.machine,
+                    p1,
+                    p2,
+                    self.rounds.into(),
+                    None,
+                    0.0,
+                    false,
+                );
+                self.scores[i] += scores.0;
+                self.scores[j] += scores.1;
+            }
+        }
+
+        let next_cells = neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, cell_neighbors)| {
+                let mut best = i;
+                for &j in cell_neighbors {
+                    if self.scores[j] > self.scores[best] {
+                        best = j;
+                    }
+                }
+                self.cells[best]
+            })
+            .collect();
+        let last_cells = std::mem::replace(&mut self.cells, next_cells);
+        GenerationStats::new(&last_cells, &self.scores)
+    }
+
+    /// Get the scores of the last played generation (by cell row by row).
+    fn standings(&self) -> Vec<(usize, T)> {
+        self.scores.iter().cloned().enumerate().collect()
+    }
+
+    /// See [`GridArena::reset`].
+    fn reset(&mut self) {
+        GridArena::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::GeneticError, machines::ExpectedValueMachine, matrices::GameMatrix, players::*,
+    };
+
+    /// Get the scores of the players in order through [`MatchTrait::standings`].
+    fn standing_scores<T, G: MatchTrait<T>>(game: &G) -> Vec<T> {
+        game.standings()
+            .into_iter()
+            .map(|(_, score)| score)
+            .collect()
+    }
+
+    fn test_arena(
+        copycats: (usize, isize),
+        allcheaters: (usize, isize),
+        allcooperates: (usize, isize),
+        grudgers: (usize, isize),
+        detectives: (usize, isize),
+        kindcopycats: (usize, isize),
+        simpletons: (usize, isize),
+        next_gen_players: Vec<usize>,
+    ) {
+        let mut scores = vec![];
+
+        let mut players: Vec<_> = vec![];
+        let mut scores_by_type = HashMap::new();
+        for (i, (c, score)) in vec![
+            copycats,
+            allcheaters,
+            allcooperates,
+            grudgers,
+            detectives,
+            kindcopycats,
+            simpletons,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            players.append(&mut vec![i; c]);
+            if c > 0 {
+                scores_by_type.insert(i, score * c as isize);
+            }
+        }
+
+        let mut arena: Arena = Arena {
+            machine: Machine::default(),
+            round_schedule: RoundSchedule::Fixed(10),
+            scores: vec![0; players.len()],
+            matches_played: vec![],
+            rounds_played: vec![],
+            initial_players: players.clone(),
+            last_players: vec![],
+            generation_history: vec![],
+            player_constructors: vec![
+                Box::new(|| Box::new(CopyCat::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(AllCheat::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(AllCooperate::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(Grudger::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(Detective::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(KindCopyCat::default()) as Box<dyn PlayerTrait<isize>>),
+                Box::new(|| Box::new(Simpleton::default()) as Box<dyn PlayerTrait<isize>>),
+            ],
+            constructor_names: [
+                "CopyCat",
+                "AllCheat",
+                "AllCooperate",
+                "Grudger",
+                "Detective",
+                "KindCopyCat",
+                "Simpleton",
+            ]
+            .map(String::from)
+            .to_vec(),
+            players,
+            strategy: GeneticStrategy::CullingElitism(5, 5),
+            pairing_mode: PairingMode::RoundRobin,
+            memory_policy: MemoryPolicy::ForgetEachPairing,
+            live_players: Default::default(),
+            seed: None,
+            noise: 0.0,
+            matchups: None,
+            observer: None,
+            roster: None,
+        };
+        arena.play();
+
+        scores.append(&mut vec![copycats.1; copycats.0]);
+        scores.append(&mut vec![allcheaters.1; allcheaters.0]);
+        scores.append(&mut vec![allcooperates.1; allcooperates.0]);
+        scores.append(&mut vec![grudgers.1; grudgers.0]);
+        scores.append(&mut vec![detectives.1; detectives.0]);
+        scores.append(&mut vec![kindcopycats.1; kindcopycats.0]);
+        scores.append(&mut vec![simpletons.1; simpletons.0]);
+
+        assert_eq!(arena.scores, scores);
+        assert_eq!(arena.scores_by_type(), scores_by_type);
+
+        arena.players.sort();
+        assert_eq!(arena.players, next_gen_players)
+    }
+
+    fn mixed_arena() -> Arena<isize> {
+        Arena::new(
+            Machine::default(),
+            vec![
+                Box::new(CopyCat::default()),
+                Box::new(AllCheat),
+                Box::new(AllCooperate),
+            ],
+            [vec![0; 9], vec![1; 8], vec![2; 8]].concat(),
+            10,
+            GeneticStrategy::CullingElitism(5, 5),
+        )
+        .unwrap()
+    }
+
+    /// Same as `mixed_arena` but with thread-safe players.
+    fn mixed_send_arena() -> SendArena<isize> {
+        SendArena::from_prototypes(
+            Machine::default(),
+            vec![
+                Box::new(CopyCat::default()),
+                Box::new(AllCheat),
+                Box::new(AllCooperate),
+            ],
+            [vec![0; 9], vec![1; 8], vec![2; 8]].concat(),
+            10,
+            GeneticStrategy::CullingElitism(5, 5),
+        )
+        .unwrap()
+    }
+
+    /// The arena of all the deterministic built-in types (6 copycats, 4 cheaters and 3 of others).
+    fn seven_type_arena() -> Arena<isize> {
+        Arena::builder()
+            .machine(Machine::default())
+            .add_type(Box::new(CopyCat::default()), 6)
+            .add_type(Box::new(AllCheat), 4)
+            .add_type(Box::new(AllCooperate), 3)
+            .add_type(Box::new(Grudger::default()), 3)
+            .add_type(Box::new(Detective::default()), 3)
+            .add_type(Box::new(KindCopyCat::default()), 3)
+            .add_type(Box::new(Simpleton::default()), 3)
+            .rounds_per_match(10)
+            .strategy(GeneticStrategy::CullingElitism(5, 5))
+            .build()
+            .unwrap()
+    }
+
+    fn arena_with_population(players: Vec<usize>) -> Arena<isize> {
+        Arena::new(
+            Machine::default(),
+            vec![
+                Box::new(CopyCat::default()),
+                Box::new(AllCheat),
+                Box::new(AllCooperate),
+                Box::new(Grudger::default()),
+            ],
+            players,
+            10,
+            GeneticStrategy::Keep,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_arena_constructor_names() {
+        let mut arena = mixed_arena();
+        assert_eq!(
+            arena.constructor_names(),
+            ["CopyCat", "AllCheat", "AllCooperate"]
+        );
+
+        assert_eq!(arena.player_name(0), "CopyCat");
+        assert_eq!(arena.player_name(24), "AllCooperate");
+
+        arena.play();
+        let names = arena.constructor_names();
+        let scores_by_name: HashMap<_, _> = arena
+            .scores_by_type()
+            .into_iter()
+            .map(|(i, score)| (names[i].clone(), score))
+            .collect();
+        assert_eq!(scores_by_name.len(), 3);
+        assert_eq!(scores_by_name["AllCooperate"], arena.scores_by_type()[&2]);
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct ContextRecorder {
+        contexts: Vec<(usize, Option<usize>)>,
+    }
+
+    impl<T> PlayerTrait<T> for ContextRecorder {
+        fn cooperation_consent(&self) -> bool {
+            true
+        }
+
+        fn observe_round_context(&mut self, round_index: usize, planned_rounds: Option<usize>) {
+            self.contexts.push((round_index, planned_rounds));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_machine_snapshot() {
+        let consents = [
+            (true, false),
+            (true, true),
+            (false, true),
+            (false, false),
+            (true, true),
+        ];
+        let mut machine = Machine::default();
+        for &c in consents.iter() {
+            machine.play(c);
+        }
+
+        let mut restored = Machine::load_snapshot(&machine.save_snapshot().unwrap()).unwrap();
+        assert_eq!(restored.round_count(), 5);
+        assert_eq!(restored.last_round(), machine.last_round());
+        for &c in consents.iter() {
+            restored.play(c);
+        }
+
+        let mut expected = Machine::default();
+        for &c in consents.iter().chain(consents.iter()) {
+            expected.play(c);
+        }
+        assert_eq!(restored.scores(), expected.scores());
+        assert_eq!(restored.round_count(), 10);
+    }
+
+    #[test]
+    fn test_nameable() {
+        use crate::traits::Nameable;
+
+        let named: [&dyn Nameable; 5] = [
+            &Machine::default(),
+            &ExpectedValueMachine::new(GameMatrix::default()),
+            &GroupMachine::default(),
+            &GeneticStrategy::Keep,
+            &GeneticStrategy::CullingElitism(1, 1),
+        ];
+        for entity in named {
+            assert!(!entity.label().is_empty());
+        }
+        assert_eq!(
+            GeneticStrategy::CullingElitism(5, 5).label(),
+            "CullingElitism"
+        );
+        assert!(!GeneticStrategy::Keep.description().is_empty());
+    }
+
+    #[test]
+    fn test_machine_resettable() {
+        use crate::traits::Resettable;
+
+        let mut machine = Machine::default();
+        machine.play((true, false));
+        Resettable::reset(&mut machine);
+        assert_eq!(machine.scores(), (0, 0));
+        assert_eq!(machine.round_count(), 0);
+    }
+
+    #[test]
+    fn test_machine_round_count() {
+        let mut machine = Machine::default();
+        assert_eq!(machine.round_count(), 0);
+        for round in 1..=3 {
+            machine.play((true, false));
+            assert_eq!(machine.round_count(), round);
+        }
+        // off the record rounds are not counted
+        machine.play_off_record((true, true));
+        assert_eq!(machine.round_count(), 3);
+        machine.reset_scores();
+        assert_eq!(machine.round_count(), 0);
+    }
+
+    #[test]
+    fn test_machine_last_round() {
+        let mut machine = Machine::default();
+        assert_eq!(machine.last_round(), None);
+        machine.play((true, false));
+        assert_eq!(machine.last_round(), Some(((true, false), (-1, 3))));
+        machine.play((false, false));
+        assert_eq!(machine.last_round(), Some(((false, false), (0, 0))));
+        machine.reset_scores();
+        assert_eq!(machine.last_round(), None);
+    }
+
+    #[test]
+    fn test_match_play_until() {
+        let reached_20 = |_, game: &Match<AllCooperate, AllCheat>| {
+            let scores = game.machine.scores();
+            scores.0 >= 20 || scores.1 >= 20
+        };
+
+        // satisfied early
+        let mut game = Match::<AllCooperate, AllCheat>::default();
+        assert_eq!(game.play_until(200, reached_20), 7);
+        assert_eq!(standing_scores(&game), [-7, 21]);
+
+        // cap hit
+        let mut game = Match::<AllCheat, AllCheat>::default();
+        let played = game.play_until(200, |_, game| game.machine.scores().0 >= 20);
+        assert_eq!(played, 200);
+        assert_eq!(game.round_count(), 200);
+
+        // nothing to play
+        let mut game = Match::<AllCooperate, AllCheat>::default();
+        assert_eq!(game.play_until(0, reached_20), 0);
+        assert_eq!(game.round_count(), 0);
+    }
+
+    #[test]
+    fn test_match_reset() {
+        let mut game = Match::<Detective, KindCopyCat>::default();
+        game.play_for_rounds(7);
+        let played = standing_scores(&game);
+
+        MatchTrait::reset(&mut game);
+        assert_eq!(standing_scores(&game), [0, 0]);
+        assert_eq!(game.round_count(), 0);
+        game.play_for_rounds(7);
+        assert_eq!(standing_scores(&game), played);
+    }
+
+    #[test]
+    fn test_arena_standings_and_reset() {
+        let mut arena = mixed_arena();
+        arena.play();
+        let first = arena.standings();
+        assert_eq!(first.len(), 25);
+        assert_eq!(first[3], (3, arena.scores[3]));
+
+        MatchTrait::reset(&mut arena);
+        assert!(arena.standings().is_empty());
+        arena.play();
+        assert_eq!(arena.standings(), first);
+    }
+
+    #[test]
+    fn test_match_round_count() {
+        let mut game = Match::<CopyCat, AllCheat>::default();
+        assert_eq!(game.round_count(), 0);
+        game.play_for_rounds(3);
+        assert_eq!(game.round_count(), 3);
+        game.play_for_rounds(3);
+        assert_eq!(game.round_count(), 6);
+
+        let game: &dyn MatchTrait<isize, Outcome = RoundOutcome<isize>> = &game;
+        assert_eq!(game.round_count(), 6);
+    }
+
+    #[test]
+    fn test_match_trait_result() {
+        let mut game = Match::<CopyCat, AllCheat>::default();
+        assert_eq!(game.result(), None);
+        game.play_for_rounds(2);
+
+        let game: &dyn MatchTrait<isize, Outcome = RoundOutcome<isize>> = &game;
+        assert_eq!(
+            game.result(),
+            Some(MatchResult {
+                scores: (-1, 3),
+                rounds_played: 2,
+                winner: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_match_round_context() {
+        let mut game = Match::<ContextRecorder, AllCheat>::default();
+        game.play_for_rounds(2);
+        game.play();
+        game.play_for_rounds(1);
+        assert_eq!(
+            game.players.0.contexts,
+            [(0, Some(2)), (1, Some(2)), (3, Some(4))]
+        );
+    }
+
+    #[test]
+    fn test_arena_round_context() {
+        let mut arena = Arena::new(
+            Machine::default(),
+            vec![Box::new(EndgameDefector::new(1)), Box::new(AllCooperate)],
+            vec![0, 1],
+            10,
+            GeneticStrategy::Keep,
+        )
+        .unwrap();
+        arena.play();
+        // cheats only in the last round of the match
+        assert_eq!(arena.scores, [9 * 2 + 3, 9 * 2 - 1]);
+    }
+
+    #[test]
+    fn test_match_observer() {
+        let recorder = VecRecorder::default();
+        let mut game = Match::<CopyCat, AllCheat>::default().with_observer(recorder.clone());
+        game.play_for_rounds(2);
+        assert_eq!(
+            recorder.events(),
+            [
+                ObserverEvent::Round(RoundOutcome {
+                    consents: (true, false),
+                    rewards: (-1, 3),
+                }),
+                ObserverEvent::Round(RoundOutcome {
+                    consents: (false, false),
+                    rewards: (0, 0),
+                }),
+            ]
+        );
+        assert_eq!(standing_scores(&game), [-1, 3]);
+    }
+
+    /// Fails to give its consent (or to memorize it) in the given round (starting from 0).
+    struct FailingPlayer {
+        failing_round: usize,
+        rounds_seen: usize,
+        failing_memory: bool,
+    }
+
+    impl TryPlayerTrait<isize> for FailingPlayer {
+        fn try_cooperation_consent(&self) -> Result<bool, PlayerError> {
+            if !self.failing_memory && self.rounds_seen == self.failing_round {
+                Err(PlayerError::Failed("the subprocess died".into()))
+            } else {
+                Ok(true)
+            }
+        }
+
+        fn try_memorize_last_game(
+            &mut self,
+            _last_consents: (bool, bool),
+            _last_rewards: (isize, isize),
+        ) -> Result<(), PlayerError> {
+            self.rounds_seen += 1;
+            if self.failing_memory && self.rounds_seen == self.failing_round + 1 {
+                Err(PlayerError::Failed("the subprocess died".into()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_match_try_play() {
+        use std::error::Error;
+
+        let mut game = Match {
+            machine: Machine::default(),
+            players: (
+                FailingPlayer {
+                    failing_round: 2,
+                    rounds_seen: 0,
+                    failing_memory: false,
+                },
+                AllCooperate,
+            ),
+            phantom: Default::default(),
+        };
+        let error = game.try_play_for_rounds(5).unwrap_err();
+        assert!(matches!(error, PlayerError::Failed(_)));
+        assert_eq!(error.source().unwrap().to_string(), "the subprocess died");
+        assert_eq!(game.machine.round_count(), 2);
+        assert_eq!(game.machine.scores(), (4, 4));
+
+        // infallible players never fail
+        let mut game = Match::<CopyCat, AllCheat>::default();
+        assert_eq!(game.try_play_for_rounds(2).unwrap().len(), 2);
+        assert_eq!(standing_scores(&game), [-1, 3]);
+    }
+
+    #[test]
+    fn test_match_try_play_failing_memory() {
+        let mut game = Match {
+            machine: Machine::default(),
+            players: (
+                FailingPlayer {
+                    failing_round: 1,
+                    rounds_seen: 0,
+                    failing_memory: true,
+                },
+                CopyCat::default(),
+            ),
+            phantom: Default::default(),
+        };
+        assert!(matches!(
+            game.try_play_for_rounds(5),
+            Err(PlayerError::Failed(_))
+        ));
+        // the machine registered the failed round so it is counted and the opponent knows it
+        assert_eq!(game.machine.round_count(), 2);
+        assert_eq!(game.machine.round_count(), 2);
+        assert_eq!(game.machine.scores(), (4, 4));
+        assert_eq!(
+            PlayerTrait::<isize>::opponent_history(&game.players.1),
+            [true, true]
+        );
+    }
+
+    /// Counts the matches started and ended by all its clones (and keeps its last own score).
+    #[derive(Debug, Default, Clone)]
+    struct MatchCounter {
+        started: Arc<Mutex<usize>>,
+        ended: Arc<Mutex<Vec<isize>>>,
+    }
+
+    impl PlayerTrait<isize> for MatchCounter {
+        fn cooperation_consent(&self) -> bool {
+            true
+        }
+
+        fn notify_match_start(&mut self) {
+            *self.started.lock().unwrap() += 1;
+        }
+
+        fn notify_match_end(&mut self, final_scores: (isize, isize)) {
+            self.ended.lock().unwrap().push(final_scores.0);
+        }
+    }
+
+    #[test]
+    fn test_arena_match_notifications() {
+        let counter = MatchCounter::default();
+        let n = 5;
+        // the counter is in the second seat against the first player
+        let mut arena = Arena::new(
+            Machine::default(),
+            vec![
+                Box::new(AllCheat),
+                Box::new(counter.clone()),
+                Box::new(AllCooperate),
+            ],
+            [vec![0], vec![1], vec![2; n - 2]].concat(),
+            2,
+            GeneticStrategy::Keep,
+        )
+        .unwrap();
+        arena.play();
+        assert_eq!(*counter.started.lock().unwrap(), n - 1);
+        let mut ended = counter.ended.lock().unwrap().clone();
+        ended.sort();
+        assert_eq!(ended, [vec![-2], vec![4; n - 2]].concat());
+    }
+
+    /// Logs how many matches it has started itself at the end of each match (shared by all its
+    /// clones).
+    #[derive(Debug, Default, Clone)]
+    struct OwnMatchCounter {
+        started: usize,
+        log: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl PlayerTrait<isize> for OwnMatchCounter {
+        fn cooperation_consent(&self) -> bool {
+            true
+        }
+
+        fn notify_match_start(&mut self) {
+            self.started += 1;
+        }
+
+        fn notify_match_end(&mut self, _: (isize, isize)) {
+            self.log.lock().unwrap().push(self.started);
+        }
+    }
+
+    #[test]
+    fn test_arena_match_notifications_persist() {
+        let logged = |policy: MemoryPolicy| {
+            let counter = OwnMatchCounter::default();
+            let mut arena = <Arena>::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(counter.clone()), 3)
+                .rounds_per_match(1)
+                .strategy(GeneticStrategy::Keep)
+                .memory_policy(policy)
+                .build()
+                .unwrap();
+            arena.run_for_generations(2);
+            let mut log = counter.log.lock().unwrap().clone();
+            log.sort();
+            log
+        };
+
+        // the fresh players of each match only see that match
+        assert_eq!(logged(MemoryPolicy::ForgetEachPairing), [1; 12]);
+        assert_eq!(
+            logged(MemoryPolicy::PersistWithinGeneration),
+            [1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2]
+        );
+        assert_eq!(
+            logged(MemoryPolicy::PersistAcrossGenerations),
+            [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
+        );
+    }
+
+    /// Logs how many games it remembers at the end of each match (shared by all its clones).
+    #[derive(Debug, Default, Clone)]
+    struct MemoryCounter {
+        memorized: usize,
+        log: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl PlayerTrait<isize> for MemoryCounter {
+        fn cooperation_consent(&self) -> bool {
+            true
+        }
+
+        fn memorize_last_game(&mut self, _: (bool, bool), _: (isize, isize)) {
+            self.memorized += 1;
+        }
+
+        fn forget_games(&mut self) {
+            self.memorized = 0;
+        }
+
+        fn notify_match_end(&mut self, _: (isize, isize)) {
+            self.log.lock().unwrap().push(self.memorized);
+        }
+    }
+
+    #[test]
+    fn test_arena_memory_policy() {
+        let logged = |policy: MemoryPolicy, strategy: GeneticStrategy| {
+            let counter = MemoryCounter::default();
+            let mut arena = <Arena>::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(counter.clone()), 3)
+                .rounds_per_match(2)
+                .strategy(strategy)
+                .memory_policy(policy)
+                .build()
+                .unwrap();
+            assert_eq!(arena.memory_policy(), policy);
+            arena.run_for_generations(2);
+            let log = counter.log.lock().unwrap().clone();
+            log
+        };
+
+        // each of the 3 players plays 2 matches of 2 rounds in each generation
+        let forgetting = logged(MemoryPolicy::ForgetEachPairing, GeneticStrategy::Keep);
+        assert_eq!(forgetting, [2; 12]);
+        let within = logged(MemoryPolicy::PersistWithinGeneration, GeneticStrategy::Keep);
+        assert_eq!(within, [[2, 2, 4, 2, 4, 4], [2, 2, 4, 2, 4, 4]].concat());
+        let across = logged(
+            MemoryPolicy::PersistAcrossGenerations,
+            GeneticStrategy::Keep,
+        );
+        assert_eq!(across, [[2, 2, 4, 2, 4, 4], [6, 6, 8, 6, 8, 8]].concat());
+        assert!(forgetting.iter().sum::<usize>() < within.iter().sum());
+        assert!(within.iter().sum::<usize>() < across.iter().sum());
+
+        // the first slot is culled for a fresh copy of the last (best by order)
+        let culled = logged(
+            MemoryPolicy::PersistAcrossGenerations,
+            GeneticStrategy::CullingElitism(1, 1),
+        );
+        assert_eq!(culled[6..], [6, 6, 8, 2, 8, 4]);
+    }
+
+    #[test]
+    fn test_arena_fresh_prototypes() {
+        let arena_with = |detective: Detective| {
+            let mut arena = Arena::new(
+                Machine::default(),
+                vec![Box::new(detective), Box::new(AllCooperate)],
+                vec![0, 1],
+                6,
+                GeneticStrategy::Keep,
+            )
+            .unwrap();
+            arena.play();
+            arena.scores
+        };
+
+        let mut detective = Detective::default();
+        for _ in 0..2 {
+            PlayerTrait::<isize>::memorize_last_game(&mut detective, (true, true), (2, 2));
+        }
+        // the pairings start the analysis from the beginning
+        assert_eq!(
+            arena_with(detective.clone()),
+            arena_with(Detective::default())
+        );
+        assert_eq!(
+            PlayerTrait::<isize>::cooperation_history(&detective).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_arena_with_factories() {
+        let builds = Arc::new(Mutex::new(0));
+        let counter = builds.clone();
+        let factory = move || {
+            *counter.lock().unwrap() += 1;
+            Box::new(CopyCat::default()) as Box<dyn PlayerTrait<isize>>
+        };
+        let mut arena = Arena::with_factories(
+            Machine::default(),
+            vec![
+                Box::new(factory),
+                // a prototype to clone
+                Box::new(Box::new(AllCheat) as Box<dyn PlayerTrait<isize>>),
+            ],
+            vec![0, 0, 0, 1],
+            2,
+            GeneticStrategy::Keep,
+        )
+        .unwrap();
+        // the name of the factory is asked once at construction
+        assert_eq!(*builds.lock().unwrap(), 1);
+        assert_eq!(arena.constructor_names(), ["CopyCat", "AllCheat"]);
+        assert_eq!(arena.player_name(0), "CopyCat");
+        assert_eq!(*builds.lock().unwrap(), 1);
+        *builds.lock().unwrap() = 0;
+
+        arena.play();
+        // the factory is not known to be deterministic so every pairing plays
+        assert_eq!(*builds.lock().unwrap(), 3 * 3);
+        assert_eq!(arena.scores, [4 + 4 - 1, 4 + 4 - 1, 4 + 4 - 1, 3 * 3]);
+    }
+
+    #[test]
+    fn test_shared_machine_between_threads() {
+        let machine = Arc::new(Mutex::new(Machine::default()));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let players: (
+                    Box<dyn SyncPlayerTrait<isize>>,
+                    Box<dyn SyncPlayerTrait<isize>>,
+                ) = (Box::new(CopyCat::default()), Box::new(AllCheat));
+                let mut game = Match {
+                    machine: machine.clone(),
+                    players,
+                    phantom: Default::default(),
+                };
+                std::thread::spawn(move || {
+                    game.play_for_rounds(3);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(machine.round_count(), 6);
+        assert_eq!(machine.scores(), (-2, 6));
+    }
+
+    #[test]
+    fn test_send_arena() {
+        let arena = mixed_send_arena();
+        let handle = std::thread::spawn(move || {
+            let mut arena = arena;
+            arena.play();
+            arena.scores
+        });
+        assert_eq!(handle.join().unwrap(), {
+            let mut arena = mixed_arena();
+            arena.play();
+            arena.scores
+        });
+    }
+
+    #[test]
+    fn test_arena_observer() {
+        let mut arena = Arena::new(
+            Machine::default(),
+            vec![Box::new(CopyCat::default()), Box::new(AllCheat)],
+            vec![0, 1],
+            2,
+            GeneticStrategy::Keep,
+        )
+        .unwrap();
+        let recorder = VecRecorder::default();
+        arena.set_observer(Box::new(recorder.clone()));
+        arena.play();
+        assert_eq!(
+            recorder.events(),
+            [
+                ObserverEvent::PairingStart(0, 1),
+                ObserverEvent::Round(RoundOutcome {
+                    consents: (true, false),
+                    rewards: (-1, 3),
+                }),
+                ObserverEvent::Round(RoundOutcome {
+                    consents: (false, false),
+                    rewards: (0, 0),
+                }),
+                ObserverEvent::PairingEnd((-1, 3)),
+                ObserverEvent::GenerationEnd(vec![0, 1], vec![-1, 3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arena_new_errors() {
+        let new = |constructors: Vec<Box<dyn PlayerTrait<isize>>>, players, rounds| {
+            Arena::new(
+                Machine::default(),
+                constructors,
+                players,
+                rounds,
+                GeneticStrategy::Keep,
+            )
+        };
+        assert!(matches!(
+            new(vec![Box::new(AllCheat)], vec![], 10),
+            Err(ArenaError::EmptyPlayers)
+        ));
+        assert!(matches!(
+            new(vec![], vec![0], 10),
+            Err(ArenaError::EmptyConstructors)
+        ));
+        assert!(matches!(
+            new(vec![Box::new(AllCheat)], vec![0], 0),
+            Err(ArenaError::RoundCountZero)
+        ));
+        assert!(matches!(
+            new(vec![Box::new(AllCheat)], vec![1], 10),
+            Err(ArenaError::UnknownPlayer)
+        ));
+
+        let Err(error) = Arena::new(
+            Machine::default(),
+            vec![Box::new(AllCheat)],
+            vec![0; 25],
+            10,
+            GeneticStrategy::CullingElitism(10, 5),
+        ) else {
+            panic!("the population must not shrink");
+        };
+        let source = std::error::Error::source(&error).expect("the genetic error must be chained");
+        assert!(matches!(
+            source.downcast_ref::<GeneticError>(),
+            Some(GeneticError::PopulationWouldShrink { remove: 10, add: 5 })
+        ));
+        assert!(std::error::Error::source(&ArenaError::EmptyPlayers).is_none());
+    }
+
+    #[test]
+    fn test_match_played_errors() {
+        let new = |rounds| {
+            Match::played(
+                Machine::default(),
+                Box::new(CopyCat::default()),
+                Box::new(AllCheat),
+                rounds,
+            )
+        };
+        assert!(matches!(new(0), Err(MatchError::ZeroRounds)));
+        let Ok(game) = new(5) else {
+            panic!("the match must be valid");
+        };
+        assert_eq!(game.round_count(), 5);
+        assert_eq!(standing_scores(&game), [-1, 3]);
+
+        let copycat: Box<dyn PlayerTrait<isize>> = Box::new(CopyCat::default());
+        assert!(same_player(&*copycat, &*copycat));
+        assert!(!same_player(&*copycat, &*copycat.clone()));
+        // stateless players share an address but are never the same player.
+        let cheat: Box<dyn PlayerTrait<isize>> = Box::new(AllCheat);
+        assert!(!same_player(&*cheat, &*cheat.clone()));
+        assert!(Match::played(
+            Machine::default(),
+            Box::new(AllCheat),
+            Box::new(AllCheat),
+            1
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_arena_builder_add_type() {
+        let mut arena = Arena::builder()
+            .machine(Machine::default())
+            .add_type(Box::new(CopyCat::default()), 4)
+            .add_type(Box::new(AllCheat), 3)
+            .add_type(Box::new(AllCooperate), 4)
+            .add_type(Box::new(Grudger::default()), 3)
+            .add_type(Box::new(Detective::default()), 4)
+            .add_type(Box::new(KindCopyCat::default()), 3)
+            .add_type(Box::new(Simpleton::default()), 4)
+            .rounds_per_match(10)
+            .strategy(GeneticStrategy::CullingElitism(5, 5))
+            .build()
+            .unwrap();
+
+        let mut expected = Arena::new(
+            Machine::default(),
+            vec![
+                Box::new(CopyCat::default()),
+                Box::new(AllCheat),
+                Box::new(AllCooperate),
+                Box::new(Grudger::default()),
                 Box::new(Detective::default()),
                 Box::new(KindCopyCat::default()),
                 Box::new(Simpleton::default()),
             ],
-            players,
-            strategy: GeneticStrategy::CullingElitism(5, 5),
+            [
+                vec![0; 4],
+                vec![1; 3],
+                vec![2; 4],
+                vec![3; 3],
+                vec![4; 4],
+                vec![5; 3],
+                vec![6; 4],
+            ]
+            .concat(),
+            10,
+            GeneticStrategy::CullingElitism(5, 5),
+        )
+        .unwrap();
+        assert_eq!(arena.players, expected.players);
+
+        arena.play();
+        expected.play();
+        assert_eq!(arena.scores, expected.scores);
+        assert_eq!(arena.players, expected.players);
+
+        assert!(matches!(
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(AllCheat), 0)
+                .rounds_per_match(10)
+                .build(),
+            Err(ArenaError::EmptyPlayers)
+        ));
+        assert!(matches!(
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(AllCheat), 2)
+                .build(),
+            Err(ArenaError::RoundCountZero)
+        ));
+    }
+
+    #[test]
+    fn test_arena_accessors() {
+        let mut arena = mixed_arena();
+        assert!(arena.scores().is_empty());
+        assert_eq!(arena.census(), [9, 8, 8]);
+        assert_eq!(arena.constructor_count(), 3);
+        assert_eq!(arena.rounds(), 10);
+
+        arena.play();
+        assert_eq!(arena.scores().len(), 25);
+        assert_eq!(arena.scores(), &arena.scores[..]);
+        assert_eq!(arena.population(), &arena.players[..]);
+        assert_eq!(arena.census().iter().sum::<usize>(), 25);
+        assert_eq!(
+            arena.census()[1],
+            arena.players.iter().filter(|&&i| i == 1).count()
+        );
+
+        assert!(matches!(
+            arena.set_rounds(0),
+            Err(ArenaError::RoundCountZero)
+        ));
+        assert_eq!(arena.rounds(), 10);
+        arena.set_rounds(5).unwrap();
+        assert_eq!(arena.rounds(), 5);
+        assert!(matches!(
+            arena.set_strategy(GeneticStrategy::CullingElitism(5, 1)),
+            Err(ArenaError::Genetic(_))
+        ));
+        arena.set_strategy(GeneticStrategy::Keep).unwrap();
+        assert!(matches!(arena.strategy(), GeneticStrategy::Keep));
+        let census = arena.census();
+        arena.play();
+        assert_eq!(arena.census(), census);
+    }
+
+    #[test]
+    fn test_arena_builder() {
+        let builder = || {
+            Arena::builder()
+                .machine(Machine::default())
+                .add_player_type(Box::new(CopyCat::default()))
+                .add_player_type(Box::new(AllCheat))
+                .add_player_type(Box::new(AllCooperate))
+                .strategy(GeneticStrategy::CullingElitism(5, 5))
         };
+
+        let mut arena = builder()
+            .initial_population([vec![0; 9], vec![1; 8], vec![2; 8]].concat())
+            .rounds_per_match(10)
+            .build()
+            .unwrap();
+        let mut expected = mixed_arena();
         arena.play();
+        expected.play();
+        assert_eq!(arena.scores, expected.scores);
+        assert_eq!(arena.players, expected.players);
 
-        scores.append(&mut vec![copycats.1; copycats.0]);
-        scores.append(&mut vec![allcheaters.1; allcheaters.0]);
-        scores.append(&mut vec![allcooperates.1; allcooperates.0]);
-        scores.append(&mut vec![grudgers.1; grudgers.0]);
-        scores.append(&mut vec![detectives.1; detectives.0]);
-        scores.append(&mut vec![kindcopycats.1; kindcopycats.0]);
-        scores.append(&mut vec![simpletons.1; simpletons.0]);
+        assert!(matches!(
+            builder().rounds_per_match(10).build(),
+            Err(ArenaError::EmptyPlayers)
+        ));
+        assert!(matches!(
+            builder().initial_population(vec![0]).build(),
+            Err(ArenaError::RoundCountZero)
+        ));
+        assert!(matches!(
+            builder()
+                .initial_population(vec![3])
+                .rounds_per_match(10)
+                .build(),
+            Err(ArenaError::UnknownPlayer)
+        ));
+        assert!(matches!(
+            <Arena>::builder()
+                .initial_population(vec![0])
+                .rounds_per_match(10)
+                .build(),
+            Err(ArenaError::MissingMachine)
+        ));
+    }
+
+    #[test]
+    fn test_arena_reset() {
+        let mut arena = mixed_arena();
+        arena.play();
+        let (scores, players) = (arena.scores.clone(), arena.players.clone());
+        arena.play();
+
+        arena.reset();
+        assert!(arena.scores.is_empty());
+        assert_eq!(arena.players, [vec![0; 9], vec![1; 8], vec![2; 8]].concat());
+        arena.play();
+        assert_eq!(arena.scores, scores);
+        assert_eq!(arena.players, players);
+    }
+
+    #[test]
+    fn test_arena_play_all_vs_one() {
+        let mut arena = arena_with_population(vec![2; 10]);
+        assert_eq!(arena.play_all_vs_one(Box::new(AllCheat)), 300);
+        assert_eq!(arena.play_all_vs_one(Box::new(CopyCat::default())), 200);
+        assert_eq!(arena.players, vec![2; 10]);
+    }
+
+    #[test]
+    fn test_arena_per_generation_stats() {
+        let mut arena = mixed_arena();
+        assert!(arena.per_generation_stats().is_empty());
+        for i in 1..=3 {
+            let stats = arena.play();
+            assert_eq!(arena.per_generation_stats().len(), i);
+            assert_eq!(arena.per_generation_stats().last(), Some(&stats));
+        }
+
+        let first = &arena.per_generation_stats()[0];
+        assert_eq!(first.min_score, 240);
+        assert_eq!(first.max_score, 312);
+        assert_eq!(first.median_score, 267);
+        assert_eq!(first.dominant_type, Some(0));
+
+        arena.reset();
+        assert!(arena.per_generation_stats().is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_arena_parallel_same_as_sequential() {
+        let mut arena = mixed_send_arena();
+        let mut sequential = mixed_arena();
+        for _ in 0..3 {
+            assert!(arena.play_pairs_parallel() == sequential.play_pairs());
+            arena.play_parallel();
+            sequential.play();
+            assert_eq!(arena.scores, sequential.scores);
+            assert_eq!(arena.players, sequential.players);
+        }
+    }
+
+    #[test]
+    fn test_arena_deduplicated_same_as_naive() {
+        let mut arena = seven_type_arena();
+        assert!(arena.plays_deterministically());
+        for _ in 0..3 {
+            assert!(arena.play_pairs_deduplicated() == arena.play_pairs());
+            arena.play();
+        }
+
+        let mut observed = seven_type_arena();
+        observed.set_observer(Box::new(VecRecorder::default()));
+        assert!(!observed.plays_deterministically());
+        let mut arena = arena_with_population(vec![0, 1]);
+        let coin: Box<dyn PlayerTrait<isize>> = Box::new(FairCoin);
+        arena.player_constructors.push(Box::new(coin));
+        arena.constructor_names.push("FairCoin".into());
+        assert!(arena.plays_deterministically());
+        arena.players.push(4);
+        assert!(!arena.plays_deterministically());
+    }
+
+    #[test]
+    fn test_arena_deduplicated_large_population() {
+        // 400 CopyCat, 300 AllCheat and 300 AllCooperate
+        let players: Vec<usize> = (0..1000)
+            .map(|i| [0, 0, 0, 0, 1, 1, 1, 2, 2, 2][i % 10])
+            .collect();
+        let mut arena = arena_with_population(players.clone());
+        assert!(arena.plays_deterministically());
+        arena.play();
+
+        let expected = |t: usize| match t {
+            0 => 399 * 20 - 300 + 300 * 20,
+            // the cheaters get nothing against each other
+            1 => 400 * 3 + 300 * 30,
+            _ => 400 * 20 + 300 * -10 + 299 * 20,
+        };
+        let expected: Vec<isize> = players.into_iter().map(expected).collect();
+        assert_eq!(arena.scores, expected);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_arena_with_seed() {
+        use crate::machines::MachineRandomizer;
+
+        let seeded_arena = || {
+            Arena::new(
+                MachineRandomizer {
+                    base: Machine::default(),
+                    consent_falsify_chance: (0.1, 0.2),
+                    random_consenter: (0.3, 0.1),
+                    rng: None,
+                },
+                vec![
+                    Box::new(CopyCat::default()),
+                    Box::new(SeededRandom::new(0)),
+                    Box::new(Grudger::default()),
+                    Box::new(Joss::default()),
+                    Box::new(StochasticCopyCat::new(0.5).unwrap()),
+                    Box::new(Grofman::default()),
+                ],
+                (0..6).flat_map(|i| vec![i; 3]).collect(),
+                10,
+                GeneticStrategy::CullingElitism(3, 3),
+            )
+            .unwrap()
+            .with_seed(42)
+        };
+
+        let (mut arena, mut replay) = (seeded_arena(), seeded_arena());
+        for _ in 0..5 {
+            arena.play();
+            replay.play();
+            assert_eq!(arena.scores, replay.scores);
+            assert_eq!(arena.players, replay.players);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_seeded_machines_off_record() {
+        use crate::machines::MachineRandomizer;
+
+        fn assert_previews_next_round(mut machine: impl MachineTrait<isize>) {
+            for consents in [(true, true), (true, false), (false, false)].repeat(10) {
+                let preview = machine.play_off_record(consents);
+                assert_eq!(machine.play_off_record(consents), preview);
+                assert_eq!(machine.play(consents), preview);
+            }
+        }
+
+        assert_previews_next_round(
+            MachineRandomizer {
+                base: Machine::default(),
+                consent_falsify_chance: (0.3, 0.3),
+                random_consenter: (0.3, 0.3),
+                rng: None,
+            }
+            .with_seed(5),
+        );
+        assert_previews_next_round(NoisyMachine::new(Machine::default(), 0.3).with_seed(5));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_match_registered_consents() {
+        use crate::machines::MachineRandomizer;
+
+        // the cooperation of the second player is always registered as cheating
+        let mut game = Match {
+            machine: MachineRandomizer {
+                base: Machine::default(),
+                consent_falsify_chance: (0.0, 1.0),
+                random_consenter: (0.0, 0.0),
+                rng: None,
+            },
+            players: (CopyCat::default(), AllCooperate),
+            phantom: Default::default(),
+        };
+        let outcomes = game.play_for_rounds(2);
+        assert_eq!(outcomes[0].consents, (true, false));
+        // retaliates to the flipped move not the intended one
+        assert_eq!(outcomes[1].consents, (false, false));
+        assert_eq!(standing_scores(&game), [-1, 3]);
+        assert_eq!(game.machine.last_round(), Some(((false, false), (0, 0))));
+    }
+
+    #[test]
+    fn test_arena_population_diversity() {
+        let monopoly = arena_with_population(vec![1; 10]);
+        assert_eq!(monopoly.population_diversity(), 0.0);
+
+        let split = arena_with_population([vec![0; 5], vec![2; 5]].concat());
+        assert!((split.population_diversity() - 0.5).abs() < f64::EPSILON);
+
+        let uniform = arena_with_population(vec![0, 1, 2, 3]);
+        assert!((uniform.population_diversity() - 0.75).abs() < f64::EPSILON);
+
+        assert_eq!(diversity(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_arena_run_for_generations() {
+        let mut arena = mixed_arena();
+        let snapshots = arena.run_for_generations(3);
+
+        let mut expected = mixed_arena();
+        assert_eq!(snapshots.len(), 3);
+        for (i, snapshot) in snapshots.into_iter().enumerate() {
+            let players = expected.players.clone();
+            expected.play();
+            assert_eq!(snapshot.generation_index, i);
+            assert_eq!(snapshot.players, players);
+            assert_eq!(snapshot.population, expected.players);
+            assert_eq!(snapshot.scores, expected.scores);
+        }
+        assert_eq!(arena.players, expected.players);
+    }
 
-        assert_eq!(arena.scores, scores);
+    #[test]
+    fn test_arena_run_until_convergence() {
+        let mut arena = mixed_arena();
+        let (generation, snapshots) = arena.run_until_convergence(100, 3).unwrap();
+        assert_eq!(snapshots.len(), generation + 1);
+        assert_eq!(generation, 6);
+        let mut population = snapshots.last().unwrap().population.clone();
+        population.sort();
+        assert_eq!(population, vec![0; 25]);
 
-        arena.players.sort();
-        assert_eq!(arena.players, next_gen_players)
+        let mut arena = mixed_arena();
+        assert!(arena.run_until_convergence(2, 3).is_err());
+    }
+
+    #[test]
+    fn test_arena_run_until_convergence_stable() {
+        let mut arena = Arena::new(
+            Machine::default(),
+            vec![Box::new(CopyCat::default())],
+            vec![0; 25],
+            10,
+            GeneticStrategy::CullingElitism(5, 5),
+        )
+        .unwrap();
+        let (generation, snapshots) = arena.run_until_convergence(100, 3).unwrap();
+        assert_eq!(generation, 2);
+        assert_eq!(snapshots.len(), 3);
     }
 
     #[test]
@@ -337,87 +3999,1050 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_arena_play_generations() {
+        let build = || {
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(CopyCat::default()), 20)
+                .add_type(Box::new(AllCheat), 5)
+                .rounds_per_match(10)
+                .strategy(GeneticStrategy::CullingElitism(5, 5))
+                .build()
+                .unwrap()
+        };
+
+        let mut arena = build();
+        let history = arena.play_generations(10);
+        assert_eq!(history.initial_census, [20, 5]);
+        assert_eq!(history.censuses.len(), 10);
+        assert_eq!(history.scores.len(), 10);
+        // the cheaters get 3 from each copycat while copycats get 20 from each other.
+        assert_eq!(
+            history.scores[0],
+            [[375; 20].to_vec(), [60; 5].to_vec()].concat()
+        );
+        assert_eq!(history.type_went_extinct(1), Some(0));
+        assert_eq!(history.type_went_extinct(0), None);
+        assert_eq!(history.final_census(), [25, 0]);
+        assert_eq!(history.dominant_type_per_generation(), [Some(0); 10]);
+
+        // same as playing one by one
+        let mut expected = build();
+        expected.play();
+        assert_eq!(history.scores[0], expected.scores);
+    }
+
+    #[test]
+    fn test_arena_play_until_stable() {
+        let mut arena = Arena::builder()
+            .machine(Machine::default())
+            .add_type(Box::new(CopyCat::default()), 10)
+            .rounds_per_match(10)
+            .strategy(GeneticStrategy::CullingElitism(5, 5))
+            .build()
+            .unwrap();
+        assert_eq!(
+            arena.play_until_stable(100, 1),
+            StableOutcome::Stable {
+                generations: 1,
+                census: vec![10]
+            }
+        );
+
+        let build = || {
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(CopyCat::default()), 20)
+                .add_type(Box::new(AllCheat), 5)
+                .rounds_per_match(10)
+                .strategy(GeneticStrategy::CullingElitism(5, 5))
+                .build()
+                .unwrap()
+        };
+        // the cheaters are gone after the first generation.
+        let outcome = build().play_until_stable(100, 3);
+        assert!(matches!(outcome, StableOutcome::Stable { .. }));
+        assert!(outcome.generations() <= 4);
+        assert_eq!(outcome.census(), [25, 0]);
+
+        assert_eq!(
+            build().play_until_stable(2, 3),
+            StableOutcome::MaxGenerationsReached {
+                generations: 2,
+                census: vec![25, 0]
+            }
+        );
+        assert_eq!(build().play_until_stable(100, 0).generations(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_arena_export_state() {
+        let build = || {
+            let roster = vec![
+                BuiltinPlayer::CopyCat(CopyCat::default()),
+                BuiltinPlayer::AllCheat(AllCheat),
+                BuiltinPlayer::Grudger(Grudger::default()),
+                BuiltinPlayer::Detective(Detective::default()),
+            ];
+            let arena = <Arena>::from_roster(
+                Machine::default(),
+                roster,
+                vec![0, 0, 0, 1, 1, 1, 2, 2, 3, 3],
+                10,
+                GeneticStrategy::CullingElitism(2, 2),
+            )
+            .unwrap();
+            #[cfg(feature = "rand")]
+            let arena = arena.with_seed(7);
+            arena
+        };
+
+        let mut arena = build();
+        arena.play_generations(3);
+        let mut restored = <Arena>::import_state(&arena.export_state().unwrap()).unwrap();
+        assert_eq!(restored.census(), arena.census());
+        restored.play_generations(3);
+
+        let mut expected = build();
+        expected.play_generations(6);
+        assert_eq!(restored.scores(), expected.scores());
+        assert_eq!(restored.population(), expected.population());
+        assert_eq!(restored.generation_history, expected.generation_history);
+
+        // arenas of arbitrary factories have no names for their players
+        let arena = <Arena>::new(
+            Machine::default(),
+            vec![Box::new(CopyCat::default())],
+            vec![0, 0],
+            10,
+            GeneticStrategy::Keep,
+        )
+        .unwrap();
+        assert!(matches!(
+            arena.export_state(),
+            Err(ArenaError::MissingRoster)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_arena_import_state_errors() {
+        let error = <Arena>::import_state("{}").err().unwrap();
+        let source = std::error::Error::source(&error).expect("the JSON error must be chained");
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+
+        let arena = <Arena>::from_roster(
+            Machine::default(),
+            vec![BuiltinPlayer::CopyCat(CopyCat::default())],
+            vec![0, 0, 0],
+            10,
+            GeneticStrategy::CullingElitism(2, 2),
+        )
+        .unwrap();
+        let json = arena
+            .export_state()
+            .unwrap()
+            .replace(r#""to_remove":2,"to_add":2"#, r#""to_remove":3,"to_add":2"#);
+        let error = <Arena>::import_state(&json).err().unwrap();
+        let source = std::error::Error::source(&error).expect("the genetic error must be chained");
+        assert!(matches!(
+            source.downcast_ref::<GeneticError>(),
+            Some(GeneticError::PopulationWouldShrink { remove: 3, add: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_census_stability() {
+        let (a, b, c) = (vec![3, 0], vec![1, 2], vec![0, 3]);
+        assert_eq!(census_stability(std::slice::from_ref(&a), 1), None);
+        assert_eq!(
+            census_stability(std::slice::from_ref(&a), 0),
+            Some(Stability::Stable)
+        );
+        assert_eq!(
+            census_stability(&[b.clone(), a.clone(), a.clone()], 1),
+            Some(Stability::Stable)
+        );
+        assert_eq!(
+            census_stability(&[b.clone(), a.clone(), a.clone()], 2),
+            None
+        );
+        assert_eq!(
+            census_stability(&[c.clone(), a.clone(), b.clone(), a.clone(), b.clone()], 2),
+            Some(Stability::Cycle)
+        );
+        assert_eq!(
+            census_stability(&[c.clone(), a.clone(), b.clone(), a.clone(), b.clone()], 3),
+            None
+        );
+        assert_eq!(census_stability(&[a.clone(), b, c, a], 1), None);
+    }
+
+    #[test]
+    fn test_arena_pairing_round_robin() {
+        let mut arena = mixed_arena();
+        assert_eq!(arena.pairing_mode(), PairingMode::RoundRobin);
+        assert_eq!(arena.play_pairs().len(), 300);
+        assert_eq!(PairingMode::RoundRobin.match_count(25), 300);
+
+        let mut expected = mixed_arena();
+        arena.set_pairing_mode(PairingMode::RoundRobin);
+        arena.play();
+        expected.play();
+        assert_eq!(arena.scores, expected.scores);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_arena_pairing_random_pairs() {
+        let mode = PairingMode::RandomPairs {
+            rounds_of_pairing: 3,
+        };
+        let build = || {
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(CopyCat::default()), 3)
+                .add_type(Box::new(AllCheat), 2)
+                .rounds_per_match(10)
+                .pairing_mode(mode)
+                .build()
+                .unwrap()
+                .with_seed(7)
+        };
+
+        let mut arena = build();
+        let pairs = arena.pairs();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(mode.match_count(5), 6);
+        for round in pairs.chunks(2) {
+            // each player plays at most once per pairing round (one sits out)
+            let mut slots: Vec<_> = round.iter().flat_map(|&(i, j, _)| [i, j]).collect();
+            slots.sort_unstable();
+            slots.dedup();
+            assert_eq!(slots.len(), 4);
+        }
+        assert_eq!(arena.play_pairs().len(), 6);
+
+        let mut again = build();
+        arena.reset();
+        arena.play();
+        again.play();
+        assert_eq!(arena.scores, again.scores);
+        assert_eq!(arena.scores.len(), 5);
+    }
+
+    #[test]
+    fn test_arena_matchup_recording() {
+        let mut arena = mixed_arena();
+        arena.play();
+        assert!(arena.matchup_history().is_empty());
+
+        let mut arena = mixed_arena().with_matchup_recording();
+        arena.play();
+        arena.play();
+        assert_eq!(arena.matchup_history().len(), 2 * 25 * 24 / 2);
+        let rates = crate::statistics::pairwise_win_rates(arena.matchup_history());
+        // cheaters always exploit the cooperators and never lose to the copycats
+        assert_eq!(rates[&(1, 2)], 1.0);
+        assert_eq!(rates[&(2, 1)], 0.0);
+        assert_eq!(rates[&(0, 1)], 0.0);
+        assert_eq!(rates[&(0, 2)], 0.0);
+
+        arena.reset();
+        assert!(arena.matchup_history().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_arena_noise() {
+        // no noise plays exactly as the machine does
+        let mut noiseless = seven_type_arena().with_noise(0.0).with_seed(5);
+        let mut expected = seven_type_arena();
+        for _ in 0..3 {
+            noiseless.play();
+            expected.play();
+            assert_eq!(noiseless.scores(), expected.scores());
+            assert_eq!(noiseless.population(), expected.population());
+        }
+
+        // certain noise flips every move
+        let matrix = GameMatrix {
+            cc: (2, 2),
+            cd: (-1, 3),
+            dc: (3, -1),
+            dd: (1, 1),
+        };
+        let mut arena = Arena::builder()
+            .machine(Machine::new(matrix))
+            .add_type(Box::new(AllCooperate), 2)
+            .add_type(Box::new(AllCheat), 2)
+            .rounds_per_match(10)
+            .build()
+            .unwrap()
+            .with_noise(1.0);
+        let report = arena.play_reported(|&score| score as f64);
+        assert_eq!(arena.noise(), 1.0);
+        assert_eq!(report.noise, 1.0);
+        assert!(report.to_string().ends_with("noise: 1"));
+        // the cooperators get dd from each other and dc from the cheaters (turned cooperators)
+        assert_eq!(arena.scores()[..2], [10 + 20 * 3, 10 + 20 * 3]);
+        assert_eq!(arena.scores()[2..], [20 - 20, 20 - 20]);
+
+        // seeded noise is reproducible
+        let noisy = || seven_type_arena().with_noise(0.2).with_seed(5);
+        let (mut a, mut b) = (noisy(), noisy());
+        a.play_generations(3);
+        b.play_generations(3);
+        assert_eq!(a.scores(), b.scores());
+        assert_ne!(a.scores(), expected.scores());
+    }
+
+    #[test]
+    fn test_round_schedule() {
+        let zero_rounds = |schedule: RoundSchedule| {
+            matches!(schedule.validate(), Err(ArenaError::RoundCountZero))
+        };
+        assert!(zero_rounds(RoundSchedule::Fixed(0)));
+        assert!(zero_rounds(RoundSchedule::PerPairing(vec![])));
+        assert!(zero_rounds(RoundSchedule::PerPairing(vec![3, 0])));
+
+        let schedule = RoundSchedule::PerPairing(vec![1, 4, 2]);
+        assert!(schedule.validate().is_ok());
+        assert_eq!(schedule.max_rounds(), 4);
+        assert!(schedule.announces_rounds());
+        let rounds: Vec<usize> = (0..5).map(|i| schedule.rounds_for(i, None)).collect();
+        assert_eq!(rounds, [1, 4, 2, 1, 4]);
+
+        let mut game = Match::<CopyCat, AllCheat>::default();
+        assert_eq!(
+            game.play_for_schedule(&RoundSchedule::Fixed(3), None).len(),
+            3
+        );
+
+        // the pairings are (0, 1), (0, 2) and (1, 2) in order
+        let mut arena = arena_with_population(vec![0, 1, 2]);
+        assert!(arena.set_round_schedule(RoundSchedule::Fixed(0)).is_err());
+        arena.set_round_schedule(schedule.clone()).unwrap();
+        assert_eq!(arena.rounds(), 4);
+        let report = arena.play_reported(|&score| score as f64);
+        assert_eq!(arena.rounds_played(), [1 + 4, 1 + 2, 4 + 2]);
+        assert_eq!(report.rounds, 1 + 4 + 2);
+        // the copycat cooperates with the cooperator in all of its 4 rounds
+        assert_eq!(arena.scores(), [-1 + 4 * 2, 3 + 2 * 3, 4 * 2 - 2]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_arena_geometric_rounds() {
+        let geometric = |continue_probability, cap| RoundSchedule::Geometric {
+            continue_probability,
+            cap,
+        };
+        assert!(matches!(
+            geometric(1.5, 10).validate(),
+            Err(ArenaError::InvalidContinueProbability)
+        ));
+        assert!(matches!(
+            geometric(0.5, 0).validate(),
+            Err(ArenaError::RoundCountZero)
+        ));
+        assert!(!geometric(0.5, 10).announces_rounds());
+
+        let arena_with = |schedule| {
+            <Arena>::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(EndgameDefector::new(2)), 4)
+                .add_type(Box::new(AllCooperate), 4)
+                .round_schedule(schedule)
+                .build()
+                .unwrap()
+        };
+
+        // no chance to continue plays a single round
+        let mut arena = arena_with(geometric(0.0, 10));
+        arena.play();
+        assert_eq!(arena.rounds_played(), [7; 8]);
+
+        // certain continuation plays up to the cap without telling the players when it ends
+        let mut arena = arena_with(geometric(1.0, 10));
+        arena.play();
+        assert_eq!(arena.rounds_played(), [7 * 10; 8]);
+        assert_eq!(arena.scores()[..4], [3 * 20 + 4 * 20; 4]);
+
+        // seeded draws are reproducible
+        let seeded = || arena_with(geometric(0.8, 50)).with_seed(11);
+        let (mut a, mut b) = (seeded(), seeded());
+        a.play_generations(2);
+        b.play_generations(2);
+        assert_eq!(a.rounds_played(), b.rounds_played());
+        assert_eq!(a.scores(), b.scores());
+        assert!(a.rounds_played().iter().any(|&r| r != a.rounds_played()[0]));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_arena_pairing_sample_opponents() {
+        let build = |mode| {
+            Arena::builder()
+                .machine(Machine::default())
+                .add_type(Box::new(CopyCat::default()), 6)
+                .add_type(Box::new(AllCheat), 2)
+                .add_type(Box::new(Grudger::default()), 2)
+                .rounds_per_match(10)
+                .strategy(GeneticStrategy::CullingElitism(2, 2))
+                .pairing_mode(mode)
+                .build()
+                .unwrap()
+                .with_seed(7)
+        };
+
+        let mode = PairingMode::SampleOpponents { k: 2 };
+        let mut arena = build(mode);
+        let pairs = arena.pairs();
+        let mut unique: Vec<_> = pairs.iter().map(|&(i, j, _)| (i, j)).collect();
+        unique.dedup();
+        assert_eq!(unique.len(), pairs.len());
+        assert!(pairs.iter().all(|&(i, j, _)| i < j));
+        assert!(pairs.len() >= 10 && pairs.len() <= mode.match_count(10));
+
+        arena.play();
+        let mut expected_counts = vec![0; 10];
+        for (i, j, _) in pairs {
+            expected_counts[i] += 1;
+            expected_counts[j] += 1;
+        }
+        assert_eq!(arena.matches_played(), expected_counts);
+        assert!(arena.matches_played().iter().all(|&m| m >= 2));
+
+        // picking everyone (or more) is a round-robin with equally weighted players
+        let mut round_robin = build(PairingMode::RoundRobin);
+        round_robin.play_generations(3);
+        for k in [9, 50] {
+            let mut arena = build(PairingMode::SampleOpponents { k });
+            assert_eq!(arena.pairs(), round_robin.pairs());
+            arena.play_generations(3);
+            assert_eq!(arena.matches_played(), [9; 10]);
+            assert_eq!(arena.scores(), round_robin.scores());
+            assert_eq!(arena.population(), round_robin.population());
+        }
+    }
+
+    #[test]
+    fn test_grid_arena_spread() {
+        // a lone cheater in the middle of cooperators
+        let build = |neighborhood| {
+            let mut cells = vec![0; 81];
+            cells[40] = 1;
+            GridArena::new(
+                Machine::default(),
+                vec![Box::new(AllCooperate), Box::new(AllCheat)],
+                9,
+                cells,
+                1,
+            )
+            .unwrap()
+            .with_neighborhood(neighborhood)
+        };
+        let render = |grid: &GridArena| {
+            grid.grid()
+                .iter()
+                .map(|row| row.iter().map(|&t| [".", "#"][t]).collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        // the cheaters beat every cooperator around them and grow a diamond
+        let mut grid = build(Neighborhood::VonNeumann);
+        let expected = [
+            [
+                ".........",
+                ".........",
+                ".........",
+                "....#....",
+                "...###...",
+                "....#....",
+                ".........",
+                ".........",
+                ".........",
+            ],
+            [
+                ".........",
+                ".........",
+                "....#....",
+                "...###...",
+                "..#####..",
+                "...###...",
+                "....#....",
+                ".........",
+                ".........",
+            ],
+            [
+                ".........",
+                "....#....",
+                "...###...",
+                "..#####..",
+                ".#######.",
+                "..#####..",
+                "...###...",
+                "....#....",
+                ".........",
+            ],
+        ];
+        for generation in expected {
+            grid.play();
+            assert_eq!(render(&grid), generation);
+        }
+
+        // the cooperators behind the first ring of victims score the most (16) and hold a square
+        let mut grid = build(Neighborhood::Moore);
+        let square = [
+            ".........",
+            ".........",
+            ".........",
+            "...###...",
+            "...###...",
+            "...###...",
+            ".........",
+            ".........",
+            ".........",
+        ];
+        for _ in 0..3 {
+            grid.play();
+            assert_eq!(render(&grid), square);
+        }
+        assert_eq!(grid.scores()[2][2..7], [13, 10, 7, 10, 13]);
+        assert_eq!(grid.scores()[3][2..7], [10, 15, 9, 15, 10]);
+
+        grid.reset();
+        assert_eq!(grid.grid()[4], [0, 0, 0, 0, 1, 0, 0, 0, 0]);
+        assert!(grid.scores().is_empty());
+    }
+
+    #[test]
+    fn test_grid_arena_neighbors() {
+        let grid = GridArena::new(
+            Machine::default(),
+            vec![Box::new(AllCooperate)],
+            3,
+            vec![0; 6],
+            1,
+        )
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.neighbors(0), [1, 3]);
+        assert_eq!(grid.neighbors(4), [1, 3, 5]);
+        let grid = grid.with_neighborhood(Neighborhood::Moore);
+        assert_eq!(grid.neighbors(0), [1, 3, 4]);
+        // the opposite edges are adjacent and the 2 rows are neighbors once
+        let torus = grid.with_toroidal(true);
+        assert_eq!(torus.neighbors(0), [5, 3, 4, 2, 1]);
+        let torus = torus.with_neighborhood(Neighborhood::VonNeumann);
+        assert_eq!(torus.neighbors(4), [1, 3, 5]);
+
+        assert!(matches!(
+            GridArena::new(
+                Machine::default(),
+                vec![Box::new(AllCooperate)],
+                4,
+                vec![0; 6],
+                1
+            ),
+            Err(ArenaError::InvalidGridShape)
+        ));
+        assert!(matches!(
+            GridArena::new(
+                Machine::default(),
+                vec![Box::new(AllCooperate)],
+                0,
+                vec![0],
+                1
+            ),
+            Err(ArenaError::InvalidGridShape)
+        ));
+    }
+
+    #[test]
+    fn test_cmp_per_match() {
+        assert_eq!(cmp_per_match(&30, 3, &25, 2), Ordering::Less);
+        assert_eq!(cmp_per_match(&30, 3, &20, 2), Ordering::Equal);
+        assert_eq!(cmp_per_match(&-3, 1, &-4, 2), Ordering::Less);
+        assert_eq!(cmp_per_match(&5, 4, &4, 4), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_arena_play_reported() {
+        let mut arena = seven_type_arena();
+
+        let report = arena.play_reported(|&score| score as f64);
+        assert_eq!(report.census_before, [6, 4, 3, 3, 3, 3, 3]);
+        assert_eq!(report.census_after, [11, 0, 3, 3, 2, 3, 3]);
+        assert_eq!(
+            report.total_scores,
+            [
+                6 * 390,
+                4 * 207,
+                3 * 297,
+                3 * 357,
+                3 * 288,
+                3 * 341,
+                3 * 353
+            ]
+        );
+        assert_eq!(report.average_scores[0], 390.0);
+        assert_eq!(report.best, Some((0, 390)));
+        assert_eq!(report.worst, Some((1, 207)));
+        assert_eq!(report.pairings, 300);
+        assert_eq!(report.rounds, 300 * 10);
+
+        let table = report.to_string();
+        assert_eq!(table.lines().count(), 10);
+        assert!(table.starts_with("type  before  after"));
+        assert!(table.ends_with("pairings: 300, rounds: 3000, noise: 0"));
+    }
+
+    #[test]
+    fn test_match_result_first_wins() {
+        let mut game = Match::<AllCheat, AllCooperate>::default();
+        game.play_for_rounds(5);
+        assert_eq!(
+            game.result(),
+            Some(MatchResult {
+                scores: (15, -5),
+                rounds_played: 5,
+                winner: Some(0),
+            })
+        );
+    }
+
+    #[test]
+    fn test_match_result_second_wins() {
+        let mut game = Match::<AllCooperate, AllCheat>::default();
+        game.play_for_rounds(5);
+        assert_eq!(
+            MatchResult::from(&game),
+            MatchResult {
+                scores: (-5, 15),
+                rounds_played: 5,
+                winner: Some(1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_match_result_tie() {
+        let mut game = Match::<CopyCat, CopyCat>::default();
+        assert_eq!(MatchResult::from(&game).winner, None);
+        assert_eq!(MatchResult::from(&game).rounds_played, 0);
+        game.play_for_rounds(5);
+        assert_eq!(
+            game.result(),
+            Some(MatchResult {
+                scores: (10, 10),
+                rounds_played: 5,
+                winner: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_match_round_outcomes() {
+        let mut game = Match::<AllCheat, CopyCat>::default();
+        let outcome = |consents, rewards| RoundOutcome { consents, rewards };
+        assert_eq!(
+            game.play_for_rounds(2),
+            [
+                outcome((false, true), (3, -1)),
+                outcome((false, false), (0, 0)),
+            ]
+        );
+        assert_eq!(game.play(), outcome((false, false), (0, 0)));
+        assert_eq!(standing_scores(&game), [3, -1]);
+    }
+
+    #[test]
+    fn test_group_match_public_goods() {
+        let mut players: Vec<Box<dyn GroupPlayerTrait<f64>>> = vec![];
+        for _ in 0..4 {
+            players.push(Box::new(GroupAdapter(AllCooperate)));
+            players.push(Box::new(GroupAdapter(AllCheat)));
+        }
+        let mut game = GroupMatch::new(GroupMachine::default(), players);
+        // the pot of 4 * 3 is split into 1.5 for each
+        let outcome = game.play();
+        assert_eq!(outcome.rewards, [0.5, 1.5].repeat(4));
+        game.play_for_rounds(9);
+        assert_eq!(standing_scores(&game), [5.0, 15.0].repeat(4));
+        assert_eq!(game.rounds_played, 10);
+    }
+
+    #[test]
+    fn test_group_match_adapter() {
+        // everyone gets the number of cooperators
+        let machine = GroupMachine::new(|consents: &[bool]| {
+            vec![consents.iter().filter(|&&c| c).count() as isize; consents.len()]
+        });
+        let mut game = GroupMatch::new(
+            machine,
+            vec![
+                Box::new(GroupAdapter(CopyCat::default())),
+                Box::new(GroupAdapter(AllCheat)),
+                Box::new(GroupAdapter(AllCheat)),
+                Box::new(GroupAdapter(AllCooperate)),
+            ],
+        );
+        assert_eq!(game.play().consents, [true, false, false, true]);
+        // only one of the three others cooperated
+        assert_eq!(game.play().consents, [false, false, false, true]);
+        assert_eq!(standing_scores(&game), [3; 4]);
+    }
+
+    #[test]
+    fn test_match_builder_lazy() {
+        let game = MatchBuilder::default()
+            .players(AllCheat, AllCooperate)
+            .build();
+        assert_eq!(game.round_count(), 0);
+        assert_eq!(standing_scores(&game), [0, 0]);
+    }
+
+    #[test]
+    fn test_match_builder_eager() {
+        let game = MatchBuilder::new()
+            .machine(Machine::default())
+            .players(AllCheat, AllCooperate)
+            .rounds(5)
+            .build();
+        assert_eq!(game.round_count(), 5);
+        assert_eq!(standing_scores(&game), [15, -5]);
+    }
+
     #[test]
     fn test_machine_default_allcheat_allcheat() {
-        let mut game = Match::<isize, AllCheat, AllCheat>::default();
+        let mut game = Match::<AllCheat, AllCheat>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (0, 0));
+        assert_eq!(standing_scores(&game), [0, 0]);
     }
 
     #[test]
     fn test_machine_default_allcooperate_allcooperate() {
-        let mut game = Match::<isize, AllCooperate, AllCooperate>::default();
+        let mut game = Match::<AllCooperate, AllCooperate>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (10, 10));
+        assert_eq!(standing_scores(&game), [10, 10]);
     }
 
     #[test]
     fn test_machine_default_copycat_copycat() {
-        let mut game = Match::<isize, CopyCat, CopyCat>::default();
+        let mut game = Match::<CopyCat, CopyCat>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (10, 10));
+        assert_eq!(standing_scores(&game), [10, 10]);
     }
 
     #[test]
     fn test_machine_default_copycat_allcooperate() {
-        let mut game = Match::<isize, AllCooperate, CopyCat>::default();
+        let mut game = Match::<AllCooperate, CopyCat>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (10, 10));
+        assert_eq!(standing_scores(&game), [10, 10]);
     }
 
     #[test]
     fn test_machine_default_allcheat_allcooperate() {
-        let mut game = Match::<isize, AllCheat, AllCooperate>::default();
+        let mut game = Match::<AllCheat, AllCooperate>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (15, -5));
+        assert_eq!(standing_scores(&game), [15, -5]);
+    }
+
+    #[test]
+    fn test_machine_default_softgrudger_allcheat() {
+        // cooperates in rounds 1, 6, 7 and 12 and both cheat in the others
+        let mut game = Match::<SoftGrudger, AllCheat>::default();
+        game.play_for_rounds(12);
+        assert_eq!(standing_scores(&game), [-4, 12]);
     }
 
     #[test]
     fn test_machine_default_allcheat_copycat() {
-        let mut game = Match::<isize, AllCheat, CopyCat>::default();
+        let mut game = Match::<AllCheat, CopyCat>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (3, -1));
+        assert_eq!(standing_scores(&game), [3, -1]);
     }
 
     #[test]
     fn test_machine_default_allcheat_kindcopycat() {
-        let mut game = Match::<isize, AllCheat, KindCopyCat>::default();
+        let mut game = Match::<AllCheat, KindCopyCat>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (6, -2));
+        assert_eq!(standing_scores(&game), [6, -2]);
     }
 
     #[test]
     fn test_machine_default_allcheat_simpleton() {
-        let mut game = Match::<isize, AllCheat, Simpleton>::default();
+        let mut game = Match::<AllCheat, Simpleton>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (9, -3));
+        assert_eq!(standing_scores(&game), [9, -3]);
     }
 
     #[test]
     fn test_machine_default_allcooperate_simpleton() {
-        let mut game = Match::<isize, AllCooperate, Simpleton>::default();
+        let mut game = Match::<AllCooperate, Simpleton>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (10, 10));
+        assert_eq!(standing_scores(&game), [10, 10]);
     }
 
     #[test]
     fn test_machine_default_allcooperate_detective() {
-        let mut game = Match::<isize, AllCooperate, Detective>::default();
+        let mut game = Match::<AllCooperate, Detective>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (4, 12));
+        assert_eq!(standing_scores(&game), [4, 12]);
     }
 
     #[test]
     fn test_machine_default_allcheat_detective() {
-        let mut game = Match::<isize, AllCheat, Detective>::default();
+        let mut game = Match::<AllCheat, Detective>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (9, -3));
+        assert_eq!(standing_scores(&game), [9, -3]);
     }
 
     #[test]
     fn test_machine_default_copycat_detective() {
-        let mut game = Match::<isize, CopyCat, Detective>::default();
+        let mut game = Match::<CopyCat, Detective>::default();
+        game.play_for_rounds(5);
+        assert_eq!(standing_scores(&game), [8, 8]);
+    }
+
+    #[test]
+    fn test_machine_default_firmbutfair_allcheat() {
+        let mut game = Match::<FirmButFair, AllCheat>::default();
+        for round in 0..10 {
+            assert_eq!(
+                PlayerTrait::<isize>::cooperation_consent(&game.players.0),
+                round % 2 == 0
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [-5, 15]);
+    }
+
+    #[test]
+    fn test_machine_default_champion_allcheat() {
+        let mut game = Match::<_, AllCheat> {
+            machine: Machine::default(),
+            players: (Champion::new(30), AllCheat),
+            phantom: Default::default(),
+        };
+        // cooperates in the first phase (3 rounds), retaliates in the copycat phase (till round 7)
+        // and keeps cheating as the opponent never cooperated
+        for round in 0..30 {
+            assert_eq!(
+                PlayerTrait::<isize>::cooperation_consent(&game.players.0),
+                round < 3
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [-3, 9]);
+    }
+
+    #[test]
+    fn test_machine_default_endgamedefector_endgamedefector() {
+        let mut game = Match::<_, _> {
+            machine: Machine::default(),
+            players: (EndgameDefector::new(2), EndgameDefector::new(2)),
+            phantom: Default::default(),
+        };
+        game.play_for_rounds(10);
+        assert_eq!(standing_scores(&game), [16, 16]);
+
+        // without the hints it is a copycat
+        let mut game = Match::<_, _> {
+            machine: Machine::default(),
+            players: (EndgameDefector::new(2), EndgameDefector::new(2)),
+            phantom: Default::default(),
+        };
+        for _ in 0..10 {
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [20, 20]);
+    }
+
+    #[test]
+    fn test_machine_default_expiringgrudger_allcheat() {
+        let mut game = Match::<_, AllCheat> {
+            machine: Machine::default(),
+            players: (ExpiringGrudger::new(2), AllCheat),
+            phantom: Default::default(),
+        };
+        for round in 0..9 {
+            assert_eq!(
+                PlayerTrait::<isize>::cooperation_consent(&game.players.0),
+                round % 3 == 0
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [-3, 9]);
+    }
+
+    #[test]
+    fn test_machine_default_punisher_allcheat() {
+        let mut game = Match::<Punisher, AllCheat>::default();
+        game.play_for_rounds(15);
+        assert_eq!(standing_scores(&game), [-1, 3]);
+    }
+
+    #[test]
+    fn test_machine_default_thresholddefector_allcooperate() {
+        let mut game = Match::<_, AllCooperate> {
+            machine: Machine::default(),
+            players: (ThresholdDefector::new(6), AllCooperate),
+            phantom: Default::default(),
+        };
+        for round in 0..10 {
+            assert_eq!(game.players.0.cooperation_consent(), round < 3);
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [27, -1]);
+    }
+
+    #[test]
+    fn test_machine_default_bully_allcooperate() {
+        let mut game = Match::<Bully, AllCooperate>::default();
         game.play_for_rounds(5);
-        assert_eq!(game.machine.scores, (8, 8));
+        assert_eq!(standing_scores(&game), [15, -5]);
+    }
+
+    #[test]
+    fn test_machine_default_bully_grudger() {
+        let mut game = Match::<Bully, Grudger>::default();
+        for consents in [
+            (false, true),
+            (false, false),
+            (true, false),
+            (true, false),
+            (true, false),
+        ] {
+            assert_eq!(
+                (
+                    PlayerTrait::<isize>::cooperation_consent(&game.players.0),
+                    PlayerTrait::<isize>::cooperation_consent(&game.players.1),
+                ),
+                consents
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [0, 8]);
+    }
+
+    #[test]
+    fn test_machine_asymmetric_opportunist_copycat() {
+        let mut game = Match::<Opportunist<isize>, CopyCat, isize, _> {
+            machine: Machine::new(GameMatrix {
+                cc: (3, 1),
+                cd: (-1, 3),
+                dc: (3, -1),
+                dd: (0, 0),
+            }),
+            players: Default::default(),
+            phantom: Default::default(),
+        };
+        // cooperates only in the first round (tied) then keeps the lead
+        for consents in [(true, true), (false, true), (false, false), (false, false)] {
+            assert_eq!(
+                (
+                    game.players.0.cooperation_consent(),
+                    PlayerTrait::<isize>::cooperation_consent(&game.players.1),
+                ),
+                consents
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [6, 0]);
+    }
+
+    #[test]
+    fn test_machine_chicken_win_shift_lose_stay() {
+        let mut game = Match::<WinShiftLoseStay<isize>, WinShiftLoseStay<isize>, isize, _> {
+            machine: Machine::new(GameMatrix {
+                cc: (3, 2),
+                cd: (1, 4),
+                dc: (4, 1),
+                dd: (0, 0),
+            }),
+            players: Default::default(),
+            phantom: Default::default(),
+        };
+        // cycles through cheating twice and cooperating once
+        for consents in [(false, false), (false, false), (true, true)].repeat(3) {
+            assert_eq!(
+                (
+                    game.players.0.cooperation_consent(),
+                    game.players.1.cooperation_consent(),
+                ),
+                consents
+            );
+            game.play();
+        }
+        assert_eq!(standing_scores(&game), [9, 6]);
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct FairCoin;
+
+    impl<T> PlayerTrait<T> for FairCoin {
+        fn cooperation_consent(&self) -> bool {
+            true
+        }
+
+        fn cooperation_probability(&self) -> f64 {
+            0.5
+        }
+    }
+
+    #[test]
+    fn test_match_expected_value() {
+        let mut game = Match::<FairCoin, FairCoin, f64, _> {
+            machine: ExpectedValueMachine::new(GameMatrix::<i32>::from_trps(3, 2, 0, -1).unwrap()),
+            players: Default::default(),
+            phantom: Default::default(),
+        };
+        game.play_expected();
+        assert_eq!(standing_scores(&game), [1.0, 1.0]);
+        game.play_expected_for_rounds(3);
+        assert_eq!(standing_scores(&game), [4.0, 4.0]);
+        assert_eq!(game.round_count(), 4);
+
+        // sampled mode plays the consents
+        game.play();
+        assert_eq!(standing_scores(&game), [6.0, 6.0]);
+    }
+
+    #[test]
+    fn test_match_expected_value_pure() {
+        let mut game = Match::<AllCheat, CopyCat, f64, _> {
+            machine: ExpectedValueMachine::new(GameMatrix::<i32>::from_trps(3, 2, 0, -1).unwrap()),
+            players: Default::default(),
+            phantom: Default::default(),
+        };
+        game.play_expected_for_rounds(5);
+        assert_eq!(standing_scores(&game), [3.0, -1.0]);
+    }
+
+    #[test]
+    fn test_match_swap_players_asymmetric() {
+        let mut game = Match::<AllCheat, AllCooperate, isize, _> {
+            machine: Machine::new(GameMatrix {
+                cc: (2, 2),
+                cd: (-1, 3),
+                dc: (5, -2),
+                dd: (0, 0),
+            }),
+            players: Default::default(),
+            phantom: Default::default(),
+        };
+        game.play_for_rounds(3);
+        assert_eq!(standing_scores(&game), [15, -6]);
+
+        let mut swapped = game.swap_players();
+        assert_eq!(standing_scores(&swapped), [0, 0]);
+        assert_eq!(swapped.round_count(), 0);
+        swapped.play_for_rounds(3);
+        assert_eq!(standing_scores(&swapped), [-3, 9]);
     }
 }
@@ -1,8 +1,16 @@
 //! Holds structs regarding payoff tables and such.
 
+use std::{
+    fmt,
+    ops::{Add, Mul},
+};
+
+use crate::{errors::MatrixError, traits::Nameable};
+
 /// Holds the status on the game.
-#[derive(Debug, Clone)]
-pub struct GameMatrix<T> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameMatrix<T = isize> {
     /// The rewards for players both cooperating.
     pub cc: (T, T),
     /// The rewards for the first player cooperating and the other not.
@@ -24,6 +32,69 @@ impl Default for GameMatrix<isize> {
     }
 }
 
+impl GameMatrix<isize> {
+    /// Stag Hunt where hunting the stag together (cooperation) pays best but is risky.
+    ///
+    /// Not a Prisoner's Dilemma as `R > T > P > S` and has no dominant strategy.
+    pub fn stag_hunt() -> Self {
+        Self {
+            cc: (4, 4),
+            cd: (1, 3),
+            dc: (3, 1),
+            dd: (2, 2),
+        }
+    }
+
+    /// Chicken where swerving (cooperation) is safe but going straight together is a disaster.
+    ///
+    /// Not a Prisoner's Dilemma as `T > R > S > P` and has no dominant strategy.
+    pub fn chicken() -> Self {
+        Self {
+            cc: (0, 0),
+            cd: (-1, 1),
+            dc: (1, -1),
+            dd: (-10, -10),
+        }
+    }
+
+    /// Battle of the Sexes where cooperation is going to the event the first player prefers.
+    ///
+    /// Not a Prisoner's Dilemma (nor symmetric) as the players only care to be at the same event.
+    /// There is no dominant strategy.
+    pub fn battle_of_the_sexes() -> Self {
+        Self {
+            cc: (3, 2),
+            cd: (0, 0),
+            dc: (0, 0),
+            dd: (2, 3),
+        }
+    }
+
+    /// Snowdrift where clearing the road (cooperation) costs 2 and benefits both by 4.
+    ///
+    /// Not a Prisoner's Dilemma as `T > R > S > P` and has no dominant strategy.
+    pub fn snowdrift() -> Self {
+        Self {
+            cc: (3, 3),
+            cd: (2, 4),
+            dc: (4, 2),
+            dd: (0, 0),
+        }
+    }
+
+    /// Harmony where cooperation pays best regardless of the opponent.
+    ///
+    /// Not a Prisoner's Dilemma as `R > T > S > P` and cooperation is the dominant strategy.
+    pub fn harmony() -> Self {
+        Self {
+            cc: (4, 4),
+            cd: (2, 3),
+            dc: (3, 2),
+            dd: (1, 1),
+        }
+    }
+}
+
 impl<T> GameMatrix<T> {
     pub fn get_for_consents(&self, consents: (bool, bool)) -> &(T, T) {
         match consents {
@@ -33,4 +104,565 @@ impl<T> GameMatrix<T> {
             (false, false) => &self.dd,
         }
     }
+
+    /// Return the matrix as seen by the second player (i.e. with the player roles swapped).
+    pub fn transpose(self) -> Self {
+        let swap = |(a, b)| (b, a);
+        Self {
+            cc: swap(self.cc),
+            cd: swap(self.dc),
+            dc: swap(self.cd),
+            dd: swap(self.dd),
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Clone> GameMatrix<T> {
+    /// Return the matrix with all the rewards multiplied by the given factor.
+    pub fn scale_by(self, factor: T) -> Self {
+        let scale = |(a, b): (T, T)| (a * factor.clone(), b * factor.clone());
+        Self {
+            cc: scale(self.cc),
+            cd: scale(self.cd),
+            dc: scale(self.dc),
+            dd: scale(self.dd),
+        }
+    }
+}
+
+/// Sum the rewards of both matrices for each outcome (e.g. to put a bonus on top of a game).
+impl<T: Add<Output = T> + Clone> Add for GameMatrix<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = |(a, b): (T, T), (c, d): (T, T)| (a + c, b + d);
+        Self {
+            cc: sum(self.cc, other.cc),
+            cd: sum(self.cd, other.cd),
+            dc: sum(self.dc, other.dc),
+            dd: sum(self.dd, other.dd),
+        }
+    }
+}
+
+impl<T: Clone + Into<f64>> GameMatrix<T> {
+    /// Return the expected rewards of the players given their chances of cooperation.
+    ///
+    /// Returns Err if any of the probabilities is not in `0.0..=1.0`.
+    pub fn expected_payoff(&self, p1_coop: f64, p2_coop: f64) -> Result<(f64, f64), MatrixError> {
+        if !(0.0..=1.0).contains(&p1_coop) || !(0.0..=1.0).contains(&p2_coop) {
+            return Err(MatrixError::InvalidProbability);
+        }
+
+        let weighted = |rewards: &(T, T), weight: f64| {
+            (
+                rewards.0.clone().into() * weight,
+                rewards.1.clone().into() * weight,
+            )
+        };
+        Ok([
+            weighted(&self.cc, p1_coop * p2_coop),
+            weighted(&self.cd, p1_coop * (1.0 - p2_coop)),
+            weighted(&self.dc, (1.0 - p1_coop) * p2_coop),
+            weighted(&self.dd, (1.0 - p1_coop) * (1.0 - p2_coop)),
+        ]
+        .into_iter()
+        .fold((0.0, 0.0), |sum, payoff| {
+            (sum.0 + payoff.0, sum.1 + payoff.1)
+        }))
+    }
+}
+
+impl<T: Add<Output = T> + Clone> GameMatrix<T> {
+    /// Return the sum of both players' rewards for each outcome (in `(cc, cd, dc, dd)` order).
+    pub fn social_welfare(&self) -> (T, T, T, T) {
+        let sum = |rewards: &(T, T)| rewards.0.clone() + rewards.1.clone();
+        (sum(&self.cc), sum(&self.cd), sum(&self.dc), sum(&self.dd))
+    }
+}
+
+impl<T: Into<f64> + Ord + Clone> GameMatrix<T> {
+    /// Map all the rewards linearly to `0.0..=1.0` (the lowest being 0 and the highest being 1).
+    ///
+    /// If all the rewards are equal, they are all mapped to 0.5.
+    pub fn normalize(&self) -> GameMatrix<f64> {
+        let rewards = [&self.cc, &self.cd, &self.dc, &self.dd];
+        let values = rewards.iter().flat_map(|(a, b)| [a, b]);
+        // the iterator is never empty
+        let min: f64 = values.clone().min().unwrap().clone().into();
+        let max: f64 = values.max().unwrap().clone().into();
+        let map = |(a, b): &(T, T)| {
+            if min == max {
+                (0.5, 0.5)
+            } else {
+                (
+                    (a.clone().into() - min) / (max - min),
+                    (b.clone().into() - min) / (max - min),
+                )
+            }
+        };
+        GameMatrix {
+            cc: map(&self.cc),
+            cd: map(&self.cd),
+            dc: map(&self.dc),
+            dd: map(&self.dd),
+        }
+    }
+}
+
+impl<T: Add<Output = T> + Clone + PartialEq> GameMatrix<T> {
+    /// Return the sum of the rewards if it is the same for all the outcomes.
+    pub fn is_constant_sum(&self) -> Option<T> {
+        let (cc, cd, dc, dd) = self.social_welfare();
+        if cc == cd && cc == dc && cc == dd {
+            Some(cc)
+        } else {
+            None
+        }
+    }
+
+    /// Check if the gains of a player are always the losses of the other (sum being the default).
+    pub fn is_zero_sum(&self) -> bool
+    where
+        T: Default,
+    {
+        self.is_constant_sum() == Some(T::default())
+    }
+}
+
+impl<T: Add<Output = T> + Into<f64> + Ord + Clone> GameMatrix<T> {
+    /// Return the ratio of the best social welfare to the one of mutual cheating (the Nash
+    /// equilibrium).
+    ///
+    /// If the social welfare of mutual cheating is zero, the result is infinite (or NaN if all
+    /// the outcomes have zero welfare).
+    pub fn price_of_anarchy(&self) -> f64 {
+        let (cc, cd, dc, dd) = self.social_welfare();
+        let best = cc.max(cd).max(dc).max(dd.clone());
+        best.into() / dd.into()
+    }
+}
+
+impl<T: PartialEq> GameMatrix<T> {
+    /// Check if both players get the same rewards in the same situations.
+    pub fn is_symmetric(&self) -> bool {
+        self.cc.0 == self.cc.1
+            && self.dd.0 == self.dd.1
+            && self.cd.0 == self.dc.1
+            && self.cd.1 == self.dc.0
+    }
+}
+
+impl<T: Copy> GameMatrix<T> {
+    /// Return the rewards as rows in `[cc, cd, dc, dd]` order.
+    pub fn to_array(&self) -> [[T; 2]; 4] {
+        [self.cc, self.cd, self.dc, self.dd].map(|(a, b)| [a, b])
+    }
+
+    /// Create a matrix from rows of rewards in `[cc, cd, dc, dd]` order.
+    pub fn from_array(arr: [[T; 2]; 4]) -> Self {
+        let [cc, cd, dc, dd] = arr.map(|[a, b]| (a, b));
+        Self { cc, cd, dc, dd }
+    }
+
+    /// Return the rewards flattened in the same order as [`Self::to_array`].
+    pub fn to_flat(&self) -> [T; 8] {
+        let [[a, b], [c, d], [e, f], [g, h]] = self.to_array();
+        [a, b, c, d, e, f, g, h]
+    }
+
+    /// Create a matrix from the flattened rewards in the same order as [`Self::from_array`].
+    pub fn from_flat(arr: [T; 8]) -> Self {
+        let [a, b, c, d, e, f, g, h] = arr;
+        Self::from_array([[a, b], [c, d], [e, f], [g, h]])
+    }
+}
+
+impl<T: Ord + Clone + fmt::Debug> GameMatrix<T> {
+    /// Create a symmetrical Prisoner's Dilemma from the standard parameters.
+    ///
+    /// These are temptation (`t`), reward (`r`), punishment (`p`) and sucker's payoff (`s`) which
+    /// must satisfy `t > r > p > s` or Err is returned.
+    pub fn from_trps(t: T, r: T, p: T, s: T) -> Result<Self, MatrixError> {
+        if !(t > r && r > p && p > s) {
+            return Err(MatrixError::PdInequalityViolated {
+                expected: "t > r > p > s".into(),
+                got: format!("t = {t:?}, r = {r:?}, p = {p:?}, s = {s:?}"),
+            });
+        }
+
+        Ok(Self {
+            cc: (r.clone(), r),
+            cd: (s.clone(), t.clone()),
+            dc: (t, s),
+            dd: (p.clone(), p),
+        })
+    }
+}
+
+impl<T: Ord> GameMatrix<T> {
+    /// Check if the matrix is a Prisoner's Dilemma for both players (`T > R > P > S`).
+    pub fn validate_prisoner_dilemma(&self) -> bool {
+        self.dc.0 > self.cc.0
+            && self.cc.0 > self.dd.0
+            && self.dd.0 > self.cd.0
+            && self.cd.1 > self.cc.1
+            && self.cc.1 > self.dd.1
+            && self.dd.1 > self.dc.1
+    }
+}
+
+impl<T: Ord> GameMatrix<T> {
+    /// Return the strictly dominant move of the first player (true for cooperation) if any.
+    pub fn dominant_strategy_p1(&self) -> Option<bool> {
+        if self.cc.0 > self.dc.0 && self.cd.0 > self.dd.0 {
+            Some(true)
+        } else if self.dc.0 > self.cc.0 && self.dd.0 > self.cd.0 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Return the strictly dominant move of the second player (true for cooperation) if any.
+    pub fn dominant_strategy_p2(&self) -> Option<bool> {
+        if self.cc.1 > self.cd.1 && self.dc.1 > self.dd.1 {
+            Some(true)
+        } else if self.cd.1 > self.cc.1 && self.dd.1 > self.dc.1 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Check if any of the players has a strictly dominant move.
+    pub fn has_dominant_strategy(&self) -> bool {
+        self.dominant_strategy_p1().is_some() || self.dominant_strategy_p2().is_some()
+    }
+}
+
+impl<T: Ord + Default> GameMatrix<T> {
+    /// Check if cooperating together does not cost any of the players (zero being the default).
+    pub fn validate_non_negative_cooperation(&self) -> bool {
+        self.cc.0 >= T::default() && self.cc.1 >= T::default()
+    }
+}
+
+impl<T> Nameable for GameMatrix<T> {
+    fn label(&self) -> &str {
+        "GameMatrix"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_error_display() {
+        let error = GameMatrix::from_trps(3, 2, 2, 0).unwrap_err();
+        assert!(matches!(error, MatrixError::PdInequalityViolated { .. }));
+        assert_eq!(
+            error.to_string(),
+            "The given parameters (t = 3, r = 2, p = 2, s = 0) do not satisfy t > r > p > s of a \
+             Prisoner's Dilemma."
+        );
+
+        let error = GameMatrix::from_trps(3, 2, 0, -1)
+            .unwrap()
+            .expected_payoff(1.5, 0.0);
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "The given probability is not in range 0.0..=1.0."
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_matrix() {
+        let json = r#"{"cc":[2,2],"cd":[-1,3],"dc":[3,-1],"dd":[0,0]}"#;
+        assert_eq!(serde_json::to_string(&GameMatrix::default()).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<GameMatrix>(json).unwrap(),
+            GameMatrix::default()
+        );
+
+        let matrix = GameMatrix {
+            cc: (3.0, 3.0),
+            cd: (0.0, 5.0),
+            dc: (5.0, 0.0),
+            dd: (1.0, 1.0),
+        };
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            serde_json::from_str::<GameMatrix<f64>>(&json).unwrap(),
+            matrix
+        );
+    }
+
+    #[test]
+    fn test_nameable() {
+        assert_eq!(GameMatrix::default().label(), "GameMatrix");
+    }
+
+    #[test]
+    fn test_from_trps() {
+        assert_eq!(
+            GameMatrix::from_trps(3, 2, 0, -1).unwrap(),
+            GameMatrix::default()
+        );
+        assert_eq!(
+            GameMatrix::from_trps(5, 3, 1, 0).unwrap(),
+            GameMatrix {
+                cc: (3, 3),
+                cd: (0, 5),
+                dc: (5, 0),
+                dd: (1, 1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_trps_invalid() {
+        assert!(GameMatrix::from_trps(2, 3, 0, -1).is_err());
+        assert!(GameMatrix::from_trps(3, 2, 2, -1).is_err());
+        assert!(GameMatrix::from_trps(3, 2, -1, 0).is_err());
+        assert!(GameMatrix::from_trps(1, 1, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_validate_prisoner_dilemma() {
+        assert!(GameMatrix::default().validate_prisoner_dilemma());
+        assert!(GameMatrix::from_trps(5, 3, 1, 0)
+            .unwrap()
+            .validate_prisoner_dilemma());
+
+        let matrix = GameMatrix {
+            cc: (3, 2),
+            ..Default::default()
+        };
+        assert!(!matrix.validate_prisoner_dilemma());
+
+        let matrix = GameMatrix {
+            dd: (0, 3),
+            ..Default::default()
+        };
+        assert!(!matrix.validate_prisoner_dilemma());
+
+        let matrix = GameMatrix {
+            cd: (1, 3),
+            ..Default::default()
+        };
+        assert!(!matrix.validate_prisoner_dilemma());
+    }
+
+    #[test]
+    fn test_validate_non_negative_cooperation() {
+        assert!(GameMatrix::default().validate_non_negative_cooperation());
+
+        let matrix = GameMatrix {
+            cc: (0, -1),
+            ..Default::default()
+        };
+        assert!(!matrix.validate_non_negative_cooperation());
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        assert!(GameMatrix::default().is_symmetric());
+        assert!(!GameMatrix {
+            cc: (2, 1),
+            ..Default::default()
+        }
+        .is_symmetric());
+        assert!(!GameMatrix {
+            cd: (-1, 4),
+            ..Default::default()
+        }
+        .is_symmetric());
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(GameMatrix::default().transpose(), GameMatrix::default());
+
+        let matrix = GameMatrix {
+            cc: (2, 1),
+            cd: (-1, 4),
+            dc: (3, -2),
+            dd: (0, 1),
+        };
+        assert_eq!(
+            matrix.clone().transpose(),
+            GameMatrix {
+                cc: (1, 2),
+                cd: (-2, 3),
+                dc: (4, -1),
+                dd: (1, 0),
+            }
+        );
+        assert_eq!(matrix.clone().transpose().transpose(), matrix);
+    }
+
+    #[test]
+    fn test_expected_payoff() {
+        let matrix: GameMatrix<i32> = GameMatrix::from_trps(3, 2, 0, -1).unwrap();
+        assert_eq!(matrix.expected_payoff(1.0, 1.0).unwrap(), (2.0, 2.0));
+        assert_eq!(matrix.expected_payoff(1.0, 0.0).unwrap(), (-1.0, 3.0));
+        assert_eq!(matrix.expected_payoff(0.0, 1.0).unwrap(), (3.0, -1.0));
+        assert_eq!(matrix.expected_payoff(0.0, 0.0).unwrap(), (0.0, 0.0));
+        // (2 - 1 + 3 + 0) / 4 for each
+        assert_eq!(matrix.expected_payoff(0.5, 0.5).unwrap(), (1.0, 1.0));
+        // 0.25 * 2 + 0.75 * -1 for the cooperator and 0.25 * 2 + 0.75 * 3 for the other
+        assert_eq!(matrix.expected_payoff(1.0, 0.25).unwrap(), (-0.25, 2.75));
+    }
+
+    #[test]
+    fn test_expected_payoff_invalid() {
+        let matrix: GameMatrix<i32> = GameMatrix::from_trps(3, 2, 0, -1).unwrap();
+        assert!(matrix.expected_payoff(-0.1, 0.5).is_err());
+        assert!(matrix.expected_payoff(0.5, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_social_welfare() {
+        assert_eq!(GameMatrix::default().social_welfare(), (4, 2, 2, 0));
+    }
+
+    #[test]
+    fn test_price_of_anarchy() {
+        let matrix: GameMatrix<i32> = GameMatrix::from_trps(3, 2, 0, -1).unwrap();
+        assert_eq!(matrix.price_of_anarchy(), f64::INFINITY);
+
+        let matrix: GameMatrix<i32> = GameMatrix::from_trps(5, 3, 1, 0).unwrap();
+        assert_eq!(matrix.price_of_anarchy(), 3.0);
+    }
+
+    #[test]
+    fn test_scale_by() {
+        assert_eq!(
+            GameMatrix::default().scale_by(2),
+            GameMatrix {
+                cc: (4, 4),
+                cd: (-2, 6),
+                dc: (6, -2),
+                dd: (0, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        let zero = GameMatrix {
+            cc: (0, 0),
+            cd: (0, 0),
+            dc: (0, 0),
+            dd: (0, 0),
+        };
+        assert_eq!(GameMatrix::default() + zero.clone(), GameMatrix::default());
+
+        let bonus = GameMatrix { cc: (1, 1), ..zero };
+        assert_eq!(GameMatrix::default().add(bonus).cc, (3, 3));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let matrix: GameMatrix<i32> = GameMatrix::from_trps(3, 2, 0, -1).unwrap();
+        assert_eq!(
+            matrix.normalize(),
+            GameMatrix {
+                cc: (0.75, 0.75),
+                cd: (0.0, 1.0),
+                dc: (1.0, 0.0),
+                dd: (0.25, 0.25),
+            }
+        );
+
+        let matrix: GameMatrix<i32> = GameMatrix {
+            cc: (1, 1),
+            cd: (1, 1),
+            dc: (1, 1),
+            dd: (1, 1),
+        };
+        assert_eq!(matrix.normalize().cd, (0.5, 0.5));
+    }
+
+    #[test]
+    fn test_dominant_strategy() {
+        let matrix = GameMatrix::default();
+        assert_eq!(matrix.dominant_strategy_p1(), Some(false));
+        assert_eq!(matrix.dominant_strategy_p2(), Some(false));
+        assert!(matrix.has_dominant_strategy());
+
+        let stag_hunt = GameMatrix {
+            cc: (4, 4),
+            cd: (0, 3),
+            dc: (3, 0),
+            dd: (3, 3),
+        };
+        assert_eq!(stag_hunt.dominant_strategy_p1(), None);
+        assert_eq!(stag_hunt.dominant_strategy_p2(), None);
+        assert!(!stag_hunt.has_dominant_strategy());
+
+        let harmony = GameMatrix {
+            cc: (3, 3),
+            cd: (2, 1),
+            dc: (1, 2),
+            dd: (0, 0),
+        };
+        assert_eq!(harmony.dominant_strategy_p1(), Some(true));
+        assert_eq!(harmony.dominant_strategy_p2(), Some(true));
+    }
+
+    #[test]
+    fn test_presets() {
+        for (matrix, dominant) in [
+            (GameMatrix::stag_hunt(), None),
+            (GameMatrix::chicken(), None),
+            (GameMatrix::battle_of_the_sexes(), None),
+            (GameMatrix::snowdrift(), None),
+            (GameMatrix::harmony(), Some(true)),
+        ] {
+            assert!(!matrix.validate_prisoner_dilemma());
+            assert_eq!(matrix.dominant_strategy_p1(), dominant);
+            assert_eq!(matrix.dominant_strategy_p2(), dominant);
+        }
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let matrix = GameMatrix::default();
+        assert_eq!(matrix.to_array(), [[2, 2], [-1, 3], [3, -1], [0, 0]]);
+        assert_eq!(matrix.to_flat(), [2, 2, -1, 3, 3, -1, 0, 0]);
+        assert_eq!(GameMatrix::from_array(matrix.to_array()), matrix);
+        assert_eq!(GameMatrix::from_flat(matrix.to_flat()), matrix);
+
+        let matrix = GameMatrix::from_flat([0.5, 1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5]);
+        assert_eq!(matrix.dc, (4.5, 5.5));
+        assert_eq!(GameMatrix::from_array(matrix.to_array()), matrix);
+    }
+
+    #[test]
+    fn test_constant_sum() {
+        let matrix = GameMatrix {
+            cc: (1, -1),
+            cd: (-1, 1),
+            dc: (1, -1),
+            dd: (-1, 1),
+        };
+        assert_eq!(matrix.is_constant_sum(), Some(0));
+        assert!(matrix.is_zero_sum());
+
+        let matrix = GameMatrix {
+            cc: (1, 2),
+            cd: (0, 3),
+            dc: (3, 0),
+            dd: (2, 1),
+        };
+        assert_eq!(matrix.is_constant_sum(), Some(3));
+        assert!(!matrix.is_zero_sum());
+
+        assert_eq!(GameMatrix::default().is_constant_sum(), None);
+        assert!(!GameMatrix::default().is_zero_sum());
+    }
 }
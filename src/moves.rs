@@ -0,0 +1,239 @@
+//! Games with any number of moves instead of only cooperating and cheating.
+//!
+//! This is a smaller parallel to the binary games (i.e. [`crate::matrices::GameMatrix`] and
+//! [`crate::matches::Match`]). Any [`crate::traits::PlayerTrait`] can play here with `bool` as its
+//! [`Move`] (see [`crate::players::BinaryPlayer`]).
+
+use std::{marker::PhantomData, ops::AddAssign};
+
+use crate::{
+    errors::MatrixError,
+    matrices::GameMatrix,
+    traits::{MatchTrait, Move, NPlayerTrait, Nameable},
+};
+
+/// Holds the rewards for each pair of moves (the first player's move being the row).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NMatrix<T, M> {
+    /// The rewards in rows of [`Move::ALL`] length.
+    rewards: Vec<(T, T)>,
+    phantom: PhantomData<M>,
+}
+
+impl<T, M: Move> NMatrix<T, M> {
+    /// Returns the matrix or Err if there is not a row and a column for each move.
+    pub fn new(rewards: Vec<Vec<(T, T)>>) -> Result<Self, MatrixError> {
+        let moves = M::ALL.len();
+        if rewards.len() != moves || rewards.iter().any(|row| row.len() != moves) {
+            return Err(MatrixError::InvalidDimensions);
+        }
+
+        Ok(Self {
+            rewards: rewards.into_iter().flatten().collect(),
+            phantom: PhantomData,
+        })
+    }
+
+    pub fn get_for_moves(&self, moves: (M, M)) -> &(T, T) {
+        &self.rewards[moves.0.index() * M::ALL.len() + moves.1.index()]
+    }
+}
+
+impl<T> From<GameMatrix<T>> for NMatrix<T, bool> {
+    fn from(matrix: GameMatrix<T>) -> Self {
+        Self {
+            rewards: vec![matrix.cc, matrix.cd, matrix.dc, matrix.dd],
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// The moves of a trust game in which players may also refuse to play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustMove {
+    Cooperate,
+    Cheat,
+    /// Do not play the round (nobody gains or loses anything).
+    Abstain,
+}
+
+impl Move for TrustMove {
+    const ALL: &'static [Self] = &[Self::Cooperate, Self::Cheat, Self::Abstain];
+}
+
+impl Default for NMatrix<isize, TrustMove> {
+    /// The default [`GameMatrix`] in which abstaining rewards nothing.
+    fn default() -> Self {
+        let matrix = GameMatrix::default();
+        Self::new(vec![
+            vec![matrix.cc, matrix.cd, (0, 0)],
+            vec![matrix.dc, matrix.dd, (0, 0)],
+            vec![(0, 0), (0, 0), (0, 0)],
+        ])
+        .unwrap()
+    }
+}
+
+/// A [`crate::players::CopyCat`] which abstains instead of cheating back.
+#[derive(Debug, Clone, Copy)]
+pub struct WaryCopyCat {
+    next_move: TrustMove,
+}
+
+impl Default for WaryCopyCat {
+    fn default() -> Self {
+        Self {
+            next_move: TrustMove::Cooperate,
+        }
+    }
+}
+
+impl Nameable for WaryCopyCat {
+    fn label(&self) -> &str {
+        "WaryCopyCat"
+    }
+}
+
+impl<T> NPlayerTrait<T, TrustMove> for WaryCopyCat {
+    fn next_move(&self) -> TrustMove {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_moves: (TrustMove, TrustMove), _last_rewards: (T, T)) {
+        self.next_move = match last_moves.1 {
+            TrustMove::Cheat => TrustMove::Abstain,
+            _ => TrustMove::Cooperate,
+        };
+    }
+
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
+}
+
+/// What happened in a single round of an [`NMatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveOutcome<T, M> {
+    /// The moves of the players.
+    pub moves: (M, M),
+    /// The rewards of the players.
+    pub rewards: (T, T),
+}
+
+/// A structure simulating two people playing a game with any number of moves.
+#[derive(Debug)]
+pub struct NMatch<T, M, P1, P2> {
+    /// The rewards of the game.
+    pub matrix: NMatrix<T, M>,
+    /// Players of the match.
+    pub players: (P1, P2),
+    /// The sum of the rewards of each player so far.
+    pub scores: (T, T),
+    /// How many rounds have been played in this match so far.
+    pub rounds_played: usize,
+}
+
+impl<T: Default, M, P1, P2> NMatch<T, M, P1, P2> {
+    pub fn new(matrix: NMatrix<T, M>, players: (P1, P2)) -> Self {
+        Self {
+            matrix,
+            players,
+            scores: Default::default(),
+            rounds_played: 0,
+        }
+    }
+}
+
+impl<T, M, P1, P2> MatchTrait<T> for NMatch<T, M, P1, P2>
+where
+    T: AddAssign<T> + Clone + Default,
+    M: Move,
+    P1: NPlayerTrait<T, M>,
+    P2: NPlayerTrait<T, M>,
+{
+    type Outcome = MoveOutcome<T, M>;
+
+    fn play(&mut self) -> MoveOutcome<T, M> {
+        let last_moves = (self.players.0.next_move(), self.players.1.next_move());
+        let last_rewards = self.matrix.get_for_moves(last_moves).clone();
+        self.scores.0 += last_rewards.0.clone();
+        self.scores.1 += last_rewards.1.clone();
+
+        // broadcast results to players
+        self.players.1.memorize_last_game(
+            (last_moves.1, last_moves.0),
+            (last_rewards.1.clone(), last_rewards.0.clone()),
+        );
+        self.players
+            .0
+            .memorize_last_game(last_moves, last_rewards.clone());
+
+        self.rounds_played += 1;
+        MoveOutcome {
+            moves: last_moves,
+            rewards: last_rewards,
+        }
+    }
+
+    fn standings(&self) -> Vec<(usize, T)> {
+        vec![(0, self.scores.0.clone()), (1, self.scores.1.clone())]
+    }
+
+    fn reset(&mut self) {
+        self.scores = Default::default();
+        self.players.0.forget_games();
+        self.players.1.forget_games();
+        self.rounds_played = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::players::{AllCheat, BinaryPlayer, CopyCat};
+
+    #[derive(Debug, Clone)]
+    struct Cheater;
+
+    impl<T> NPlayerTrait<T, TrustMove> for Cheater {
+        fn next_move(&self) -> TrustMove {
+            TrustMove::Cheat
+        }
+    }
+
+    #[test]
+    fn test_nmatrix_dimensions() {
+        assert!(NMatrix::<isize, TrustMove>::new(vec![vec![(0, 0); 3]; 2]).is_err());
+        assert!(NMatrix::<isize, TrustMove>::new(vec![vec![(0, 0); 2]; 3]).is_err());
+        assert!(NMatrix::<isize, bool>::new(vec![vec![(0, 0); 2]; 2]).is_ok());
+    }
+
+    #[test]
+    fn test_nmatrix_from_game_matrix() {
+        let matrix = NMatrix::from(GameMatrix::default());
+        assert_eq!(matrix.get_for_moves((true, false)), &(-1, 3));
+        assert_eq!(matrix.get_for_moves((false, true)), &(3, -1));
+    }
+
+    #[test]
+    fn test_nmatch_wary_copycat_cheater() {
+        let mut game = NMatch::new(NMatrix::default(), (WaryCopyCat::default(), Cheater));
+        let outcomes = game.play_for_rounds(3);
+        assert_eq!(
+            outcomes.iter().map(|o| o.moves.0).collect::<Vec<_>>(),
+            [TrustMove::Cooperate, TrustMove::Abstain, TrustMove::Abstain]
+        );
+        assert_eq!(game.scores, (-1, 3));
+        assert_eq!(game.players.0.label(), "WaryCopyCat");
+    }
+
+    #[test]
+    fn test_nmatch_binary_players() {
+        let mut game = NMatch::new(
+            NMatrix::from(GameMatrix::default()),
+            (BinaryPlayer(CopyCat::default()), BinaryPlayer(AllCheat)),
+        );
+        game.play_for_rounds(5);
+        assert_eq!(game.scores, (-1, 3));
+    }
+}
@@ -1,13 +1,27 @@
 //! A series of deterministic players introduced in the original game.
 //!
-//! Enable "rand" feature for the player Random.
+//! Enable "rand" feature for the randomized players (e.g. [`Random`], [`Joss`] and [`Grofman`]).
 
-use crate::{traits::PlayerTrait, worm_bools::RiseOnlyBool};
+use std::{borrow::Cow, collections::VecDeque, fmt, ops::AddAssign};
+
+#[cfg(any(feature = "rand", doc))]
+use crate::errors::PlayerError;
+#[cfg(feature = "serde")]
+use crate::{errors::RosterError, traits::SerializablePlayer};
+use crate::{
+    traits::{GroupPlayerTrait, NPlayerTrait, Nameable, PlayerTrait, Resettable, StrategyId},
+    worm_bools::RiseOnlyBool,
+};
 
 /// Start with cooperating and repeat whatever the opponent does the last round.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyCat {
     last_enemy_consent: Option<bool>,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
 }
 
 impl<T> PlayerTrait<T> for CopyCat {
@@ -17,37 +31,90 @@ impl<T> PlayerTrait<T> for CopyCat {
 
     fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
         self.last_enemy_consent = Some(last_consents.1);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
     }
 
     fn forget_games(&mut self) {
         *self = Default::default();
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        "CopyCat".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("CopyCat", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
 }
 
 /// A player who always cooperates.
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AllCooperate;
 
 impl<T> PlayerTrait<T> for AllCooperate {
     fn cooperation_consent(&self) -> bool {
         true
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        "AllCooperate".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("AllCooperate", ())
+    }
 }
 
 /// A player who always cheats.
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AllCheat;
 
 impl<T> PlayerTrait<T> for AllCheat {
     fn cooperation_consent(&self) -> bool {
         false
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        "AllCheat".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("AllCheat", ())
+    }
 }
 
 /// Cooperate till never been cheated.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Grudger {
     been_cheated: RiseOnlyBool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
 }
 
 impl<T> PlayerTrait<T> for Grudger {
@@ -57,15 +124,38 @@ impl<T> PlayerTrait<T> for Grudger {
 
     fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
         self.been_cheated.rise_if(!last_consents.1);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
     }
 
     fn forget_games(&mut self) {
         *self = Self::default();
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Grudger".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Grudger", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
 }
 
 /// Plays a fixed strategy and if cheated turns to copycat else cheats.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Detective {
     been_cheated_in_analysing: RiseOnlyBool,
     /// How far the detective is in the analysis.
@@ -74,6 +164,10 @@ pub struct Detective {
     analysing_strategy: Vec<bool>,
     /// The memory of what to play next.
     next_strategy: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
 }
 
 impl Detective {
@@ -83,6 +177,8 @@ impl Detective {
             analysing_strategy: initial_strategy,
             analysing_stage: 1,
             been_cheated_in_analysing: Default::default(),
+            history: vec![],
+            opponent_history: vec![],
         }
     }
 }
@@ -99,6 +195,8 @@ impl<T> PlayerTrait<T> for Detective {
     }
 
     fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
         // Return the current step of the analyses and count one upward (None if ended).
         if self.analysing_stage < self.analysing_strategy.len() {
             // memorize if enemy did retaliate ever
@@ -114,16 +212,48 @@ impl<T> PlayerTrait<T> for Detective {
         self.next_strategy = *self.analysing_strategy.first().unwrap_or(&true);
         self.analysing_stage = 1;
         self.been_cheated_in_analysing = Default::default();
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        let moves = self
+            .analysing_strategy
+            .iter()
+            .map(|&consent| if consent { 'C' } else { 'D' })
+            .collect::<String>();
+        format!("Detective({moves})").into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Detective", &self.analysing_strategy)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
     }
 }
 
 /// Copy kitten, allows for a number of repeated cheats before retaliating.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KindCopyCat {
     /// Only retaliates after the number of mistakes is passed.
     mistakes_allowed: usize,
     /// How many times has been cheated in row.
     cheated_in_row: usize,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
 }
 
 impl KindCopyCat {
@@ -131,6 +261,8 @@ impl KindCopyCat {
         Self {
             mistakes_allowed,
             cheated_in_row: 0,
+            history: vec![],
+            opponent_history: vec![],
         }
     }
 }
@@ -155,10 +287,34 @@ impl<T> PlayerTrait<T> for KindCopyCat {
                 self.cheated_in_row += 1;
             }
         }
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
     }
 
     fn forget_games(&mut self) {
         self.cheated_in_row = Default::default();
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("KindCopyCat({})", self.mistakes_allowed).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("KindCopyCat", self.mistakes_allowed)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
     }
 }
 
@@ -166,14 +322,23 @@ impl<T> PlayerTrait<T> for KindCopyCat {
 ///
 /// Note that this player sees what are the results of the machine. Since machines and arenas may
 /// not be fair, he repeats the last thing, even if it was a mistake.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Simpleton {
     next_move: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
 }
 
 impl Default for Simpleton {
     fn default() -> Self {
-        Self { next_move: true }
+        Self {
+            next_move: true,
+            history: vec![],
+            opponent_history: vec![],
+        }
     }
 }
 
@@ -188,192 +353,2844 @@ impl<T> PlayerTrait<T> for Simpleton {
         } else {
             !last_consents.0
         };
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
     }
 
     fn forget_games(&mut self) {
         *self = Default::default();
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Simpleton".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Simpleton", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
 }
 
-/// Randomly consents or doesn't (requires "rand" feature).
-#[cfg(any(feature = "rand", doc))]
-#[derive(Default, Debug, Clone, Copy)]
-pub struct Random;
+/// Start by cheating and keep cheating while the opponent cooperates, cooperate once punished.
+///
+/// This is the mirror image of [`Simpleton`], exploiting overly nice players.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bully {
+    last_enemy_consent: Option<bool>,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
 
-#[cfg(any(feature = "rand", doc))]
-impl<T> PlayerTrait<T> for Random {
+impl<T> PlayerTrait<T> for Bully {
     fn cooperation_consent(&self) -> bool {
-        rand::random()
+        !self.last_enemy_consent.unwrap_or(true)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.last_enemy_consent = Some(last_consents.1);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
 
-    fn all_types<P: PlayerTrait<usize>>(
-        player: &mut P,
-        initial: bool,
-        enemy_consents_to_reaction: &[((bool, bool), bool)],
-    ) {
-        assert_eq!(player.cooperation_consent(), initial); // first must be true
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
 
-        for &(consents, reaction) in enemy_consents_to_reaction {
-            player.memorize_last_game(consents, (1, 1));
-            assert_eq!(player.cooperation_consent(), reaction);
-        }
+    fn name(&self) -> Cow<'_, str> {
+        "Bully".into()
     }
 
-    #[test]
-    fn test_copycat() {
-        all_types(
-            &mut CopyCat::default(),
-            true,
-            &[
-                ((true, true), true),
-                ((true, false), false),
-                ((false, false), false),
-                ((false, true), true),
-            ],
-        );
+    fn is_deterministic(&self) -> bool {
+        true
     }
 
-    #[test]
-    fn test_all_cooperate() {
-        all_types(
-            &mut AllCooperate::default(),
-            true,
-            &[
-                ((true, true), true),
-                ((true, false), true),
-                ((false, false), true),
-                ((false, true), true),
-            ],
-        );
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Bully", ())
     }
 
-    #[test]
-    fn test_all_cheat() {
-        all_types(
-            &mut AllCheat::default(),
-            false,
-            &[
-                ((true, true), false),
-                ((true, false), false),
-                ((false, false), false),
-                ((false, true), false),
-            ],
-        );
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
     }
 
-    #[test]
-    fn test_grudger() {
-        all_types(
-            &mut Grudger::default(),
-            true,
-            &[
-                ((true, true), true),
-                ((true, false), false),
-                ((false, true), false),
-                ((true, true), false),
-            ],
-        );
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
 
-        all_types(
-            &mut Grudger::default(),
-            true,
-            &[
-                ((true, true), true),
-                ((true, true), true),
-                ((false, true), true),
-                ((true, true), true),
-                ((true, false), false),
-                ((true, true), false),
-                ((true, true), false),
-            ],
-        );
+/// Cooperate while behind or tied on the total rewards and cheat only while strictly ahead.
+///
+/// This player is aware of the rewards so it behaves differently on asymmetrical matrices.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Opportunist<T> {
+    /// Sum of the rewards this player got so far.
+    own_total: T,
+    /// Sum of the rewards the opponent got so far.
+    enemy_total: T,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl<T> PlayerTrait<T> for Opportunist<T>
+where
+    T: AddAssign<T> + PartialOrd + Clone + Default,
+{
+    fn cooperation_consent(&self) -> bool {
+        self.own_total <= self.enemy_total
     }
 
-    #[test]
-    fn test_detective() {
-        all_types(
-            &mut Detective::default(),
-            true,
-            &[
-                // default initial strat
-                ((false, true), false),
-                ((false, false), true),
-                ((false, true), true),
-                // copycat, coz been cheated
-                ((false, false), false),
-                ((false, false), false),
-                ((false, false), false),
-                ((false, true), true),
-                ((false, true), true),
-                ((false, false), false),
-                ((true, false), false),
-                ((true, true), true),
-            ],
-        );
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (T, T)) {
+        self.own_total += last_rewards.0;
+        self.enemy_total += last_rewards.1;
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
 
-        all_types(
-            &mut Detective::default(),
-            true,
-            &[
-                // default initial strat
-                ((false, true), false),
-                ((false, true), true),
-                ((false, true), true),
-                // cheater, coz not been cheated
-                ((false, true), false),
-                ((false, true), false),
-                ((false, false), false),
-                ((false, true), false),
-                ((false, true), false),
-                ((false, false), false),
-                ((true, false), false),
-                ((true, true), false),
-            ],
-        );
+    fn forget_games(&mut self) {
+        *self = Default::default();
     }
 
-    #[test]
-    fn test_kindcopycat() {
-        all_types(
-            &mut KindCopyCat::default(),
-            true,
-            &[
-                ((false, true), true),
-                ((true, true), true),
-                ((true, false), true),
-                ((false, true), true),
-                ((true, false), true),
-                ((false, true), true),
-                ((true, false), true),
-                ((false, false), false),
-                ((false, true), true),
-                ((false, false), true),
-                ((true, false), false),
-                ((true, false), false),
-                ((false, true), true),
-            ],
-        );
+    fn name(&self) -> Cow<'_, str> {
+        "Opportunist".into()
     }
 
-    #[test]
-    fn test_simpleton() {
-        all_types(
-            &mut Simpleton::default(),
-            true,
-            &[
-                ((true, true), true),
-                ((true, true), true),
-                ((true, false), false),
-                ((false, true), false),
-                ((false, false), true),
-                ((true, false), false),
-                ((false, true), false),
-            ],
-        );
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Opportunist", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Switch the move if the reward did not drop since the round before, otherwise repeat it.
+///
+/// The inverse of [`Simpleton`] which cheats first (unless constructed otherwise) and compares the
+/// rewards instead of the consents, which is useful for studying Chicken-like matrices.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WinShiftLoseStay<T> {
+    /// Whether to cooperate in the first round.
+    initial_move: bool,
+    /// The reward of the last round.
+    last_reward: Option<T>,
+    /// The memory of what to play next.
+    next_move: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl<T> WinShiftLoseStay<T> {
+    pub fn new(initial_move: bool) -> Self {
+        Self {
+            initial_move,
+            last_reward: None,
+            next_move: initial_move,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> Default for WinShiftLoseStay<T> {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<T> PlayerTrait<T> for WinShiftLoseStay<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (T, T)) {
+        let won = matches!(&self.last_reward, Some(previous) if last_rewards.0 >= *previous);
+        self.next_move = last_consents.0 != won;
+        self.last_reward = Some(last_rewards.0);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.last_reward = None;
+        self.next_move = self.initial_move;
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("WinShiftLoseStay({})", self.initial_move).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("WinShiftLoseStay", self.initial_move)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Cooperate unless been the sucker last round (cooperated while the opponent cheated).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirmButFair {
+    next_move: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl Default for FirmButFair {
+    fn default() -> Self {
+        Self {
+            next_move: true,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for FirmButFair {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.next_move = last_consents != (true, false);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "FirmButFair".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("FirmButFair", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// A three-phase player depending on the match length (Axelrod's Champion).
+///
+/// Cooperates for the first tenth of the match, plays [`CopyCat`] till the first quarter and then
+/// cooperates unless the opponent has cooperated less than 60% of the rounds so far.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Champion {
+    /// How many rounds the match is going to be.
+    total_rounds: usize,
+    /// How many rounds are played so far.
+    rounds_played: usize,
+    /// How many times the opponent has cooperated.
+    enemy_cooperations: usize,
+    /// What the opponent did in the last round.
+    last_enemy_consent: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl Champion {
+    pub fn new(total_rounds: usize) -> Self {
+        Self {
+            total_rounds,
+            rounds_played: 0,
+            enemy_cooperations: 0,
+            last_enemy_consent: true,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for Champion {
+    fn cooperation_consent(&self) -> bool {
+        if self.rounds_played < self.total_rounds / 10 {
+            true
+        } else if self.rounds_played < self.total_rounds / 4 {
+            self.last_enemy_consent
+        } else {
+            // cooperation rate of at least 60% (no rounds is not a reason to cheat)
+            self.enemy_cooperations * 5 >= self.rounds_played * 3
+        }
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.rounds_played += 1;
+        if last_consents.1 {
+            self.enemy_cooperations += 1;
+        }
+        self.last_enemy_consent = last_consents.1;
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Self::new(self.total_rounds);
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("Champion({})", self.total_rounds).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Champion", self.total_rounds)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// A [`CopyCat`] which cheats in the last rounds of the match if told about the match length.
+///
+/// Without round hints (see [`PlayerTrait::observe_round_context`]) it is the same as [`CopyCat`].
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EndgameDefector {
+    base: CopyCat,
+    /// How many of the last rounds to cheat in.
+    defect_last: usize,
+    /// The last hint given as the current round and the total rounds.
+    round_hint: Option<(usize, usize)>,
+}
+
+impl EndgameDefector {
+    pub fn new(defect_last: usize) -> Self {
+        Self {
+            base: Default::default(),
+            defect_last,
+            round_hint: None,
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for EndgameDefector {
+    fn cooperation_consent(&self) -> bool {
+        match self.round_hint {
+            Some((current, total)) if total.saturating_sub(current) <= self.defect_last => false,
+            _ => <CopyCat as PlayerTrait<T>>::cooperation_consent(&self.base),
+        }
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (T, T)) {
+        self.base.memorize_last_game(last_consents, last_rewards);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Self::new(self.defect_last);
+    }
+
+    fn observe_round_context(&mut self, round_index: usize, planned_rounds: Option<usize>) {
+        self.round_hint = planned_rounds.map(|total| (round_index, total));
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("EndgameDefector({})", self.defect_last).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("EndgameDefector", self.defect_last)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        <CopyCat as PlayerTrait<T>>::cooperation_history(&self.base)
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        <CopyCat as PlayerTrait<T>>::opponent_history(&self.base)
+    }
+}
+
+/// Axelrod's tournament entry by Rudy Nydegger, deciding on the outcomes of the last three rounds.
+///
+/// Plays [`CopyCat`] for the first three rounds, except that it cheats in the third round if the
+/// opponent cheated first and cooperated second. Afterwards, each of the last three outcomes is
+/// encoded as a digit (0 if both cooperated, 2 if only the opponent cheated, 1 if only this
+/// player cheated and 3 if both cheated) and the player cheats if `16a1 + 4a2 + a3` (`a1` being
+/// the last round) is one of [`Self::DEFECTING_CODES`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nydegger {
+    /// The last three consents (the last round first).
+    last_rounds: [(bool, bool); 3],
+    /// How many rounds are played (up to 3).
+    rounds_played: usize,
+    /// The memory of what to play next.
+    next_move: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl Nydegger {
+    /// The codes of the last three outcomes in which the player cheats.
+    pub const DEFECTING_CODES: [usize; 16] =
+        [1, 6, 7, 17, 22, 30, 31, 33, 38, 39, 45, 49, 54, 55, 58, 61];
+
+    /// Encode an outcome to a digit of the code.
+    fn encode(consents: (bool, bool)) -> usize {
+        match consents {
+            (true, true) => 0,
+            (false, true) => 1,
+            (true, false) => 2,
+            (false, false) => 3,
+        }
+    }
+}
+
+impl Default for Nydegger {
+    fn default() -> Self {
+        Self {
+            last_rounds: [(true, true); 3],
+            rounds_played: 0,
+            next_move: true,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for Nydegger {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.last_rounds.rotate_right(1);
+        self.last_rounds[0] = last_consents;
+        if self.rounds_played < 3 {
+            self.rounds_played += 1;
+        }
+
+        self.next_move = match self.rounds_played {
+            1 => last_consents.1,
+            2 => last_consents.1 && self.last_rounds[1].1,
+            _ => {
+                let code = self
+                    .last_rounds
+                    .iter()
+                    .rev()
+                    .fold(0, |code, &consents| code / 4 + 16 * Self::encode(consents));
+                !Self::DEFECTING_CODES.contains(&code)
+            }
+        };
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Nydegger".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Nydegger", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Cooperate until the total rewards reach the target and cheat for the rest of the match.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThresholdDefector<T> {
+    /// The total reward after which the player turns greedy.
+    target: T,
+    /// Sum of the rewards this player got so far.
+    bank: T,
+    /// Whether the target is ever reached.
+    greedy: RiseOnlyBool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl<T: Default> ThresholdDefector<T> {
+    pub fn new(target: T) -> Self {
+        Self {
+            target,
+            bank: Default::default(),
+            greedy: Default::default(),
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for ThresholdDefector<T>
+where
+    T: AddAssign<T> + PartialOrd + Clone + Default + fmt::Debug,
+{
+    fn cooperation_consent(&self) -> bool {
+        !self.greedy
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (T, T)) {
+        self.bank += last_rewards.0;
+        self.greedy.rise_if(self.bank >= self.target);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.bank = Default::default();
+        self.greedy = Default::default();
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("ThresholdDefector({:?})", self.target).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        // the rewards may not be hashable so the target is told apart by its text
+        StrategyId::new("ThresholdDefector", format!("{:?}", self.target))
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Once cheated, cheat for a fixed number of rounds and then cooperate again.
+///
+/// Cheats seen while holding the grudge do not extend it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExpiringGrudger {
+    /// How many rounds to cheat after being cheated.
+    grudge_length: usize,
+    /// How many rounds of the grudge are left.
+    remaining_grudge: usize,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl ExpiringGrudger {
+    pub fn new(grudge_length: usize) -> Self {
+        Self {
+            grudge_length,
+            remaining_grudge: 0,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for ExpiringGrudger {
+    fn cooperation_consent(&self) -> bool {
+        self.remaining_grudge == 0
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        if self.remaining_grudge > 0 {
+            self.remaining_grudge -= 1;
+        } else if !last_consents.1 {
+            self.remaining_grudge = self.grudge_length;
+        }
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.remaining_grudge = 0;
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("ExpiringGrudger({})", self.grudge_length).into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("ExpiringGrudger", self.grudge_length)
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Cooperate by default and punish each cheat for as many rounds as the cheats suffered so far.
+///
+/// Cheats seen while punishing are counted, but their punishment starts after the current one.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Punisher {
+    /// How many times has been cheated in total.
+    offenses: usize,
+    /// How many rounds of punishment are left.
+    remaining_punishment: usize,
+    /// The punishment to apply after the current one is done.
+    pending_punishment: usize,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl<T> PlayerTrait<T> for Punisher {
+    fn cooperation_consent(&self) -> bool {
+        self.remaining_punishment == 0
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        let punishing = self.remaining_punishment > 0;
+        if punishing {
+            self.remaining_punishment -= 1;
+        }
+
+        if !last_consents.1 {
+            self.offenses += 1;
+            if punishing {
+                self.pending_punishment += self.offenses;
+            } else {
+                self.remaining_punishment += self.offenses;
+            }
+        }
+
+        if self.remaining_punishment == 0 {
+            self.remaining_punishment = std::mem::take(&mut self.pending_punishment);
+        }
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Punisher".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Punisher", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Once cheated, cheat for a few rounds, then cooperate for a few rounds as a peace offering.
+///
+/// Cheats seen during a cycle of punishment and peace restart it after the current one is done.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftGrudger {
+    /// How many rounds to cheat after being cheated.
+    punishment_length: usize,
+    /// How many rounds to cooperate after the punishment.
+    peace_length: usize,
+    /// The moves left in the current cycle.
+    pending_moves: VecDeque<bool>,
+    /// Whether been cheated since the current cycle started.
+    offended: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl SoftGrudger {
+    pub fn new(punishment_length: usize, peace_length: usize) -> Self {
+        Self {
+            punishment_length,
+            peace_length,
+            pending_moves: VecDeque::with_capacity(punishment_length + peace_length),
+            offended: false,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl Default for SoftGrudger {
+    fn default() -> Self {
+        Self::new(4, 2)
+    }
+}
+
+impl<T> PlayerTrait<T> for SoftGrudger {
+    fn cooperation_consent(&self) -> bool {
+        self.pending_moves.front().copied().unwrap_or(true)
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.pending_moves.pop_front();
+        self.offended |= !last_consents.1;
+
+        if self.pending_moves.is_empty() && self.offended {
+            self.offended = false;
+            self.pending_moves
+                .extend(std::iter::repeat(false).take(self.punishment_length));
+            self.pending_moves
+                .extend(std::iter::repeat(true).take(self.peace_length));
+        }
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.pending_moves.clear();
+        self.offended = false;
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!(
+            "SoftGrudger({}, {})",
+            self.punishment_length, self.peace_length
+        )
+        .into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("SoftGrudger", (self.punishment_length, self.peace_length))
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// How [`AdaptivePavlov`] classified its opponent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum AdaptivePavlovMode {
+    /// Not enough rounds are observed yet.
+    #[default]
+    Unclassified,
+    /// The opponent mostly cooperates.
+    Cooperative,
+    /// The opponent mostly cheats.
+    Defector,
+    /// The opponent is random or something in between.
+    Other,
+}
+
+/// Classify the opponent every few rounds and play a counter-strategy against it.
+///
+/// Plays [`CopyCat`] until the first classification. Afterwards, it plays [`CopyCat`] against
+/// cooperative opponents, [`AllCheat`] against defectors and [`Simpleton`] against the rest.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptivePavlov {
+    /// The opponent's consents since the last classification.
+    observations: [bool; Self::CLASSIFICATION_ROUNDS],
+    /// How many rounds are played since the last classification.
+    phase: usize,
+    /// The current classification of the opponent.
+    mode: AdaptivePavlovMode,
+    /// The memory of what to play next.
+    next_move: bool,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+impl AdaptivePavlov {
+    /// How many rounds are observed for each classification.
+    pub const CLASSIFICATION_ROUNDS: usize = 6;
+
+    /// Classify the opponent based on how many times it cooperated in the observed rounds.
+    fn classify(&self) -> AdaptivePavlovMode {
+        match self.observations.iter().filter(|&&c| c).count() {
+            c if c + 1 >= Self::CLASSIFICATION_ROUNDS => AdaptivePavlovMode::Cooperative,
+            0 | 1 => AdaptivePavlovMode::Defector,
+            _ => AdaptivePavlovMode::Other,
+        }
+    }
+}
+
+impl Default for AdaptivePavlov {
+    fn default() -> Self {
+        Self {
+            observations: Default::default(),
+            phase: 0,
+            mode: Default::default(),
+            next_move: true,
+            history: vec![],
+            opponent_history: vec![],
+        }
+    }
+}
+
+impl<T> PlayerTrait<T> for AdaptivePavlov {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.observations[self.phase] = last_consents.1;
+        self.phase += 1;
+        if self.phase == Self::CLASSIFICATION_ROUNDS {
+            self.phase = 0;
+            self.mode = self.classify();
+        }
+
+        self.next_move = match self.mode {
+            AdaptivePavlovMode::Unclassified | AdaptivePavlovMode::Cooperative => last_consents.1,
+            AdaptivePavlovMode::Defector => false,
+            AdaptivePavlovMode::Other => last_consents.0 == last_consents.1,
+        };
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        *self = Default::default();
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "AdaptivePavlov".into()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("AdaptivePavlov", ())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Randomly consents or doesn't (requires "rand" feature).
+#[cfg(any(feature = "rand", doc))]
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Random;
+
+#[cfg(any(feature = "rand", doc))]
+impl Random {
+    /// Get a reproducible random player rolling from the given seed (see [`SeededRandom`]).
+    pub fn with_seed(seed: u64) -> SeededRandom {
+        SeededRandom::new(seed)
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T> PlayerTrait<T> for Random {
+    fn cooperation_consent(&self) -> bool {
+        rand::random()
+    }
+
+    fn cooperation_probability(&self) -> f64 {
+        0.5
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Random".into()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Random", ())
+    }
+}
+
+/// A reproducible [`Random`] which rolls its moves from a seeded generator (requires "rand").
+///
+/// The move is rolled after each game (and on construction) and kept for the next consent. Clones
+/// share the generator state hence roll the same moves from the point of cloning.
+#[cfg(any(feature = "rand", doc))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeededRandom {
+    /// The seed the generator started from.
+    seed: u64,
+    /// The seed of the next roll.
+    rng_state: u64,
+    /// The memory of what to play next.
+    next_move: bool,
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl SeededRandom {
+    pub fn new(seed: u64) -> Self {
+        let mut rng_state = seed;
+        Self {
+            seed,
+            next_move: roll_from(0.5, &mut rng_state),
+            rng_state,
+        }
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T> PlayerTrait<T> for SeededRandom {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, _last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.next_move = roll_from(0.5, &mut self.rng_state);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    fn cooperation_probability(&self) -> f64 {
+        0.5
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("SeededRandom({})", self.seed).into()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("SeededRandom", self.seed)
+    }
+}
+
+/// Any of the built-in players (i.e. to hold or serialize a roster of different players).
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type")
+)]
+pub enum BuiltinPlayer {
+    CopyCat(CopyCat),
+    AllCooperate(AllCooperate),
+    AllCheat(AllCheat),
+    Grudger(Grudger),
+    Detective(Detective),
+    KindCopyCat(KindCopyCat),
+    Simpleton(Simpleton),
+    Bully(Bully),
+    Opportunist(Opportunist<isize>),
+    WinShiftLoseStay(WinShiftLoseStay<isize>),
+    FirmButFair(FirmButFair),
+    Champion(Champion),
+    EndgameDefector(EndgameDefector),
+    Nydegger(Nydegger),
+    ThresholdDefector(ThresholdDefector<isize>),
+    ExpiringGrudger(ExpiringGrudger),
+    Punisher(Punisher),
+    SoftGrudger(SoftGrudger),
+    AdaptivePavlov(AdaptivePavlov),
+    #[cfg(any(feature = "rand", doc))]
+    Random(Random),
+    #[cfg(any(feature = "rand", doc))]
+    SeededRandom(SeededRandom),
+    #[cfg(any(feature = "rand", doc))]
+    Joss(Joss),
+    #[cfg(any(feature = "rand", doc))]
+    StochasticCopyCat(StochasticCopyCat),
+    #[cfg(any(feature = "rand", doc))]
+    Grofman(Grofman),
+}
+
+impl BuiltinPlayer {
+    /// Get the wrapped player.
+    fn as_player(&self) -> &dyn PlayerTrait<isize> {
+        match self {
+            Self::CopyCat(p) => p,
+            Self::AllCooperate(p) => p,
+            Self::AllCheat(p) => p,
+            Self::Grudger(p) => p,
+            Self::Detective(p) => p,
+            Self::KindCopyCat(p) => p,
+            Self::Simpleton(p) => p,
+            Self::Bully(p) => p,
+            Self::Opportunist(p) => p,
+            Self::WinShiftLoseStay(p) => p,
+            Self::FirmButFair(p) => p,
+            Self::Champion(p) => p,
+            Self::EndgameDefector(p) => p,
+            Self::Nydegger(p) => p,
+            Self::ThresholdDefector(p) => p,
+            Self::ExpiringGrudger(p) => p,
+            Self::Punisher(p) => p,
+            Self::SoftGrudger(p) => p,
+            Self::AdaptivePavlov(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Random(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::SeededRandom(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Joss(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::StochasticCopyCat(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Grofman(p) => p,
+        }
+    }
+
+    /// Get the wrapped player as mutable.
+    fn as_player_mut(&mut self) -> &mut dyn PlayerTrait<isize> {
+        match self {
+            Self::CopyCat(p) => p,
+            Self::AllCooperate(p) => p,
+            Self::AllCheat(p) => p,
+            Self::Grudger(p) => p,
+            Self::Detective(p) => p,
+            Self::KindCopyCat(p) => p,
+            Self::Simpleton(p) => p,
+            Self::Bully(p) => p,
+            Self::Opportunist(p) => p,
+            Self::WinShiftLoseStay(p) => p,
+            Self::FirmButFair(p) => p,
+            Self::Champion(p) => p,
+            Self::EndgameDefector(p) => p,
+            Self::Nydegger(p) => p,
+            Self::ThresholdDefector(p) => p,
+            Self::ExpiringGrudger(p) => p,
+            Self::Punisher(p) => p,
+            Self::SoftGrudger(p) => p,
+            Self::AdaptivePavlov(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Random(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::SeededRandom(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Joss(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::StochasticCopyCat(p) => p,
+            #[cfg(any(feature = "rand", doc))]
+            Self::Grofman(p) => p,
+        }
+    }
+}
+
+impl PlayerTrait<isize> for BuiltinPlayer {
+    fn cooperation_consent(&self) -> bool {
+        self.as_player().cooperation_consent()
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (isize, isize)) {
+        self.as_player_mut()
+            .memorize_last_game(last_consents, last_rewards)
+    }
+
+    fn forget_games(&mut self) {
+        self.as_player_mut().forget_games()
+    }
+
+    fn observe_round_context(&mut self, round_index: usize, planned_rounds: Option<usize>) {
+        self.as_player_mut()
+            .observe_round_context(round_index, planned_rounds)
+    }
+
+    fn notify_match_start(&mut self) {
+        self.as_player_mut().notify_match_start()
+    }
+
+    fn notify_match_end(&mut self, final_scores: (isize, isize)) {
+        self.as_player_mut().notify_match_end(final_scores)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.as_player_mut().reseed(seed)
+    }
+
+    fn is_deterministic(&self) -> bool {
+        self.as_player().is_deterministic()
+    }
+
+    fn cooperation_probability(&self) -> f64 {
+        self.as_player().cooperation_probability()
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        self.as_player().name()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        self.as_player().strategy_id()
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        self.as_player().cooperation_history()
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        self.as_player().opponent_history()
+    }
+}
+
+/// Implement [`SerializablePlayer`] for the given players and register them for the rosters.
+#[cfg(feature = "serde")]
+macro_rules! register_serializable {
+    ($($(#[$attr:meta])* $player:ident$(<$t:ty>)?),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl SerializablePlayer for $player$(<$t>)? {
+                fn type_name(&self) -> &'static str {
+                    stringify!($player)
+                }
+
+                fn state(&self) -> serde_json::Result<serde_json::Value> {
+                    serde_json::to_value(self)
+                }
+            }
+        )*
+
+        /// Restore a player of the registered type from its state (`None` if not registered).
+        fn deserialize_player(
+            type_name: &str,
+            state: serde_json::Value,
+        ) -> Option<serde_json::Result<Box<dyn SerializablePlayer>>> {
+            match type_name {
+                $(
+                    $(#[$attr])*
+                    stringify!($player) => Some(
+                        serde_json::from_value::<$player$(<$t>)?>(state)
+                            .map(|p| Box::new(p) as Box<dyn SerializablePlayer>),
+                    ),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+register_serializable!(
+    CopyCat,
+    AllCooperate,
+    AllCheat,
+    Grudger,
+    Detective,
+    KindCopyCat,
+    Simpleton,
+    Bully,
+    Opportunist<isize>,
+    WinShiftLoseStay<isize>,
+    FirmButFair,
+    Champion,
+    EndgameDefector,
+    Nydegger,
+    ThresholdDefector<isize>,
+    ExpiringGrudger,
+    Punisher,
+    SoftGrudger,
+    AdaptivePavlov,
+    #[cfg(feature = "rand")]
+    Random,
+    #[cfg(feature = "rand")]
+    SeededRandom,
+    #[cfg(feature = "rand")]
+    Joss,
+    #[cfg(feature = "rand")]
+    StochasticCopyCat,
+    #[cfg(feature = "rand")]
+    Grofman,
+);
+
+/// Get the players as a JSON array of `{"type": ..., "state": ...}` objects (requires "serde").
+#[cfg(feature = "serde")]
+pub fn serialize_roster(roster: &[Box<dyn SerializablePlayer>]) -> Result<String, RosterError> {
+    let entries = roster
+        .iter()
+        .map(|player| {
+            Ok(serde_json::json!({
+                "type": player.type_name(),
+                "state": player.state()?,
+            }))
+        })
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    Ok(serde_json::to_string(&entries)?)
+}
+
+/// Restore the players of a roster given by [`serialize_roster`] (requires "serde").
+///
+/// Returns Err if the JSON is not a valid roster or any of the types is not a registered built-in
+/// player.
+#[cfg(feature = "serde")]
+pub fn deserialize_roster(json: &str) -> Result<Vec<Box<dyn SerializablePlayer>>, RosterError> {
+    /// An entry of the roster.
+    #[derive(serde::Deserialize)]
+    struct Entry {
+        #[serde(rename = "type")]
+        type_name: String,
+        state: serde_json::Value,
+    }
+
+    serde_json::from_str::<Vec<Entry>>(json)?
+        .into_iter()
+        .map(
+            |entry| match deserialize_player(&entry.type_name, entry.state) {
+                Some(player) => Ok(player?),
+                None => Err(RosterError::UnknownType(entry.type_name)),
+            },
+        )
+        .collect()
+}
+
+/// Implement [`Nameable`] for the given players with the name of their types.
+macro_rules! impl_nameable {
+    ($($player:ident$(<$t:ident>)?),* $(,)?) => {
+        $(
+            impl$(<$t>)? Nameable for $player$(<$t>)? {
+                fn label(&self) -> &str {
+                    stringify!($player)
+                }
+            }
+        )*
+    };
+}
+
+impl_nameable!(
+    CopyCat,
+    AllCooperate,
+    AllCheat,
+    Grudger,
+    Detective,
+    KindCopyCat,
+    Simpleton,
+    Bully,
+    Opportunist<T>,
+    WinShiftLoseStay<T>,
+    FirmButFair,
+    Champion,
+    EndgameDefector,
+    Nydegger,
+    ThresholdDefector<T>,
+    ExpiringGrudger,
+    Punisher,
+    SoftGrudger,
+    AdaptivePavlov,
+);
+
+#[cfg(any(feature = "rand", doc))]
+impl_nameable!(Random, SeededRandom, Joss, StochasticCopyCat, Grofman);
+
+impl Nameable for BuiltinPlayer {
+    fn label(&self) -> &str {
+        match self {
+            Self::CopyCat(p) => p.label(),
+            Self::AllCooperate(p) => p.label(),
+            Self::AllCheat(p) => p.label(),
+            Self::Grudger(p) => p.label(),
+            Self::Detective(p) => p.label(),
+            Self::KindCopyCat(p) => p.label(),
+            Self::Simpleton(p) => p.label(),
+            Self::Bully(p) => p.label(),
+            Self::Opportunist(p) => p.label(),
+            Self::WinShiftLoseStay(p) => p.label(),
+            Self::FirmButFair(p) => p.label(),
+            Self::Champion(p) => p.label(),
+            Self::EndgameDefector(p) => p.label(),
+            Self::Nydegger(p) => p.label(),
+            Self::ThresholdDefector(p) => p.label(),
+            Self::ExpiringGrudger(p) => p.label(),
+            Self::Punisher(p) => p.label(),
+            Self::SoftGrudger(p) => p.label(),
+            Self::AdaptivePavlov(p) => p.label(),
+            #[cfg(any(feature = "rand", doc))]
+            Self::Random(p) => p.label(),
+            #[cfg(any(feature = "rand", doc))]
+            Self::SeededRandom(p) => p.label(),
+            #[cfg(any(feature = "rand", doc))]
+            Self::Joss(p) => p.label(),
+            #[cfg(any(feature = "rand", doc))]
+            Self::StochasticCopyCat(p) => p.label(),
+            #[cfg(any(feature = "rand", doc))]
+            Self::Grofman(p) => p.label(),
+        }
+    }
+}
+
+impl<P: Nameable> Nameable for GroupAdapter<P> {
+    fn label(&self) -> &str {
+        self.0.label()
+    }
+}
+
+impl<P: Nameable> Nameable for BinaryPlayer<P> {
+    fn label(&self) -> &str {
+        self.0.label()
+    }
+}
+
+/// Implement [`Resettable`] for the given players by forgetting their games.
+macro_rules! impl_resettable {
+    ($($player:ident),* $(,)?) => {
+        $(
+            impl Resettable for $player {
+                fn reset(&mut self) {
+                    // the memory of these players does not depend on the type of rewards
+                    PlayerTrait::<isize>::forget_games(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_resettable!(
+    CopyCat,
+    AllCooperate,
+    AllCheat,
+    Grudger,
+    Detective,
+    KindCopyCat,
+    Simpleton,
+    Bully,
+    FirmButFair,
+    Champion,
+    EndgameDefector,
+    Nydegger,
+    ExpiringGrudger,
+    Punisher,
+    SoftGrudger,
+    AdaptivePavlov,
+    BuiltinPlayer,
+);
+
+#[cfg(any(feature = "rand", doc))]
+impl_resettable!(Random, SeededRandom, Joss, StochasticCopyCat, Grofman);
+
+impl<T> Resettable for Opportunist<T>
+where
+    T: AddAssign<T> + PartialOrd + Clone + Default,
+{
+    fn reset(&mut self) {
+        PlayerTrait::<T>::forget_games(self)
+    }
+}
+
+impl<T> Resettable for WinShiftLoseStay<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn reset(&mut self) {
+        PlayerTrait::<T>::forget_games(self)
+    }
+}
+
+impl<T> Resettable for ThresholdDefector<T>
+where
+    T: AddAssign<T> + PartialOrd + Clone + Default + fmt::Debug,
+{
+    fn reset(&mut self) {
+        PlayerTrait::<T>::forget_games(self)
+    }
+}
+
+/// Plays a [`PlayerTrait`] in groups (see [`GroupPlayerTrait`]).
+///
+/// The rest of the group is seen as a single opponent which cooperated if most of the others did
+/// (ties included) and got the same reward.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GroupAdapter<P>(pub P);
+
+impl<T: Clone, P: PlayerTrait<T> + Clone> GroupPlayerTrait<T> for GroupAdapter<P> {
+    fn cooperation_consent(&self) -> bool {
+        self.0.cooperation_consent()
+    }
+
+    fn memorize_last_group_game(
+        &mut self,
+        own_consent: bool,
+        other_cooperators: usize,
+        others: usize,
+        last_reward: T,
+    ) {
+        let group_consent = other_cooperators * 2 >= others;
+        self.0.memorize_last_game(
+            (own_consent, group_consent),
+            (last_reward.clone(), last_reward),
+        );
+    }
+
+    fn forget_games(&mut self) {
+        self.0.forget_games()
+    }
+}
+
+/// Plays a [`PlayerTrait`] with `bool` moves in [`crate::moves`] (cooperation being true).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinaryPlayer<P>(pub P);
+
+impl<T, P: PlayerTrait<T> + Clone> NPlayerTrait<T, bool> for BinaryPlayer<P> {
+    fn next_move(&self) -> bool {
+        self.0.cooperation_consent()
+    }
+
+    fn memorize_last_game(&mut self, last_moves: (bool, bool), last_rewards: (T, T)) {
+        self.0.memorize_last_game(last_moves, last_rewards)
+    }
+
+    fn forget_games(&mut self) {
+        self.0.forget_games()
+    }
+}
+
+/// Return an error if the given chance is not a valid probability.
+#[cfg(any(feature = "rand", doc))]
+fn check_probability(probability: f64) -> Result<f64, PlayerError> {
+    if (0.0..=1.0).contains(&probability) {
+        Ok(probability)
+    } else {
+        Err(PlayerError::InvalidProbability)
+    }
+}
+
+/// Roll a chance which is true with the given probability.
+#[cfg(any(feature = "rand", doc))]
+fn roll(probability: f64) -> bool {
+    rand::random::<f64>() < probability
+}
+
+/// Same as [`roll`] but from the seeded state (advancing it) if any.
+#[cfg(any(feature = "rand", doc))]
+fn roll_seeded(probability: f64, state: &mut Option<u64>) -> bool {
+    match state {
+        Some(state) => roll_from(probability, state),
+        None => roll(probability),
+    }
+}
+
+/// Same as [`roll`] but from the given state (advancing it).
+///
+/// The state is a SplitMix64 generator so the players holding it stay small and serializable.
+#[cfg(any(feature = "rand", doc))]
+fn roll_from(probability: f64, state: &mut u64) -> bool {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    // the top 53 bits as a fraction in `0..1`
+    ((z >> 11) as f64 / (1u64 << 53) as f64) < probability
+}
+
+/// A [`CopyCat`] which sneakily cheats instead of cooperating by chance (requires "rand" feature).
+///
+/// The chance is rolled after each game (and on construction) and kept for the next consent.
+#[cfg(any(feature = "rand", doc))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Joss {
+    base: CopyCat,
+    /// The chance of cheating when the copycat would cooperate (`0..=1`).
+    sneak_probability: f64,
+    /// Whether to cheat in the next round regardless of the copycat.
+    sneaking: bool,
+    /// The seed of the next roll (rolling from the thread generator if not seeded).
+    rng_state: Option<u64>,
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl Joss {
+    /// Returns the player or Err if the probability is not in `0..=1`.
+    pub fn new(sneak_probability: f64) -> Result<Self, PlayerError> {
+        Ok(Self {
+            base: Default::default(),
+            sneak_probability: check_probability(sneak_probability)?,
+            sneaking: roll(sneak_probability),
+            rng_state: None,
+        })
+    }
+
+    /// Roll the chances from the given seed for reproducible games (see
+    /// [`PlayerTrait::reseed`]).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        PlayerTrait::<isize>::reseed(&mut self, seed);
+        self
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl Default for Joss {
+    fn default() -> Self {
+        Self::new(0.1).unwrap()
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T> PlayerTrait<T> for Joss {
+    fn cooperation_consent(&self) -> bool {
+        !self.sneaking && <CopyCat as PlayerTrait<T>>::cooperation_consent(&self.base)
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), last_rewards: (T, T)) {
+        self.base.memorize_last_game(last_consents, last_rewards);
+        self.sneaking = roll_seeded(self.sneak_probability, &mut self.rng_state);
+    }
+
+    fn forget_games(&mut self) {
+        <CopyCat as PlayerTrait<T>>::forget_games(&mut self.base);
+        self.sneaking = roll_seeded(self.sneak_probability, &mut self.rng_state);
+    }
+
+    /// Reroll the chance of sneaking in the next round from the seed.
+    fn reseed(&mut self, seed: u64) {
+        self.rng_state = Some(seed);
+        self.sneaking = roll_seeded(self.sneak_probability, &mut self.rng_state);
+    }
+
+    fn cooperation_probability(&self) -> f64 {
+        if <CopyCat as PlayerTrait<T>>::cooperation_consent(&self.base) {
+            1.0 - self.sneak_probability
+        } else {
+            0.0
+        }
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("Joss({})", self.sneak_probability).into()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Joss", self.sneak_probability.to_bits())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        <CopyCat as PlayerTrait<T>>::cooperation_history(&self.base)
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        <CopyCat as PlayerTrait<T>>::opponent_history(&self.base)
+    }
+}
+
+/// Imitates the opponent's last move by chance, otherwise repeats its own (requires "rand").
+///
+/// Starts by cooperating. The chance is rolled after each game and the move is kept for the next
+/// consent.
+#[cfg(any(feature = "rand", doc))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticCopyCat {
+    /// The chance of copying the opponent instead of repeating itself (`0..=1`).
+    imitation_probability: f64,
+    /// The memory of what to play next.
+    next_move: bool,
+    /// The seed of the next roll (rolling from the thread generator if not seeded).
+    rng_state: Option<u64>,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl StochasticCopyCat {
+    /// Returns the player or Err if the probability is not in `0..=1`.
+    pub fn new(imitation_probability: f64) -> Result<Self, PlayerError> {
+        Ok(Self {
+            imitation_probability: check_probability(imitation_probability)?,
+            next_move: true,
+            rng_state: None,
+            history: vec![],
+            opponent_history: vec![],
+        })
+    }
+
+    /// Roll the chances from the given seed for reproducible games (see
+    /// [`PlayerTrait::reseed`]).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng_state = Some(seed);
+        self
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T> PlayerTrait<T> for StochasticCopyCat {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.next_move = if roll_seeded(self.imitation_probability, &mut self.rng_state) {
+            last_consents.1
+        } else {
+            last_consents.0
+        };
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.next_move = true;
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng_state = Some(seed);
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("StochasticCopyCat({})", self.imitation_probability).into()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("StochasticCopyCat", self.imitation_probability.to_bits())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+/// Cooperates if both players agreed last round, otherwise by chance (requires "rand" feature).
+///
+/// Starts by cooperating. The chance is rolled after each game and the move is kept for the next
+/// consent.
+#[cfg(any(feature = "rand", doc))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grofman {
+    /// The chance of cooperating after a disagreement (`0..=1`).
+    cooperation_probability: f64,
+    /// The memory of what to play next.
+    next_move: bool,
+    /// The seed of the next roll (rolling from the thread generator if not seeded).
+    rng_state: Option<u64>,
+    /// The past consents of the player.
+    history: Vec<bool>,
+    /// The past consents of the opponent.
+    opponent_history: Vec<bool>,
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl Grofman {
+    /// Returns the player or Err if the probability is not in `0..=1`.
+    pub fn new(cooperation_probability: f64) -> Result<Self, PlayerError> {
+        Ok(Self {
+            cooperation_probability: check_probability(cooperation_probability)?,
+            next_move: true,
+            rng_state: None,
+            history: vec![],
+            opponent_history: vec![],
+        })
+    }
+
+    /// Roll the chances from the given seed for reproducible games (see
+    /// [`PlayerTrait::reseed`]).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng_state = Some(seed);
+        self
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl Default for Grofman {
+    fn default() -> Self {
+        Self::new(2.0 / 7.0).unwrap()
+    }
+}
+
+#[cfg(any(feature = "rand", doc))]
+impl<T> PlayerTrait<T> for Grofman {
+    fn cooperation_consent(&self) -> bool {
+        self.next_move
+    }
+
+    fn memorize_last_game(&mut self, last_consents: (bool, bool), _last_rewards: (T, T)) {
+        self.next_move = last_consents.0 == last_consents.1
+            || roll_seeded(self.cooperation_probability, &mut self.rng_state);
+        self.history.push(last_consents.0);
+        self.opponent_history.push(last_consents.1);
+    }
+
+    fn forget_games(&mut self) {
+        self.next_move = true;
+        self.history.clear();
+        self.opponent_history.clear();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng_state = Some(seed);
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("Grofman({})", self.cooperation_probability).into()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new("Grofman", self.cooperation_probability.to_bits())
+    }
+
+    fn cooperation_history(&self) -> &[bool] {
+        &self.history
+    }
+
+    fn opponent_history(&self) -> &[bool] {
+        &self.opponent_history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::fresh_clone;
+
+    fn all_types<P: PlayerTrait<usize>>(
+        player: &mut P,
+        initial: bool,
+        enemy_consents_to_reaction: &[((bool, bool), bool)],
+    ) {
+        assert_eq!(player.cooperation_consent(), initial); // first must be true
+
+        for &(consents, reaction) in enemy_consents_to_reaction {
+            player.memorize_last_game(consents, (1, 1));
+            assert_eq!(player.cooperation_consent(), reaction);
+        }
+    }
+
+    #[test]
+    fn test_copycat() {
+        all_types(
+            &mut CopyCat::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_copycat_cooperation_history() {
+        let mut player = CopyCat::default();
+        assert!(PlayerTrait::<usize>::cooperation_history(&player).is_empty());
+        for consents in [(true, false), (false, false), (false, true), (true, true)] {
+            player.memorize_last_game(consents, (1, 1));
+        }
+        assert_eq!(
+            PlayerTrait::<usize>::cooperation_history(&player),
+            [true, false, false, true]
+        );
+        PlayerTrait::<usize>::forget_games(&mut player);
+        assert!(PlayerTrait::<usize>::cooperation_history(&player).is_empty());
+        assert!(PlayerTrait::<usize>::cooperation_history(&AllCooperate).is_empty());
+    }
+
+    #[test]
+    fn test_opponent_history() {
+        let mut players = [
+            BuiltinPlayer::CopyCat(Default::default()),
+            BuiltinPlayer::Grudger(Default::default()),
+            BuiltinPlayer::Detective(Default::default()),
+        ];
+        for player in players.iter_mut() {
+            for consents in [(true, false), (false, true), (true, true)] {
+                player.memorize_last_game(consents, (1, 1));
+            }
+            assert_eq!(player.opponent_history(), [false, true, true]);
+            assert_eq!(player.cooperation_history(), [true, false, true]);
+            player.forget_games();
+            assert!(player.opponent_history().is_empty());
+            assert!(player.cooperation_history().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_history_of_memory_players() {
+        let players: Vec<Box<dyn PlayerTrait<isize>>> = vec![
+            Box::new(KindCopyCat::default()),
+            Box::new(Simpleton::default()),
+            Box::new(WinShiftLoseStay::default()),
+            Box::new(Champion::new(10)),
+            Box::new(EndgameDefector::new(2)),
+            Box::new(ExpiringGrudger::new(2)),
+            Box::new(SoftGrudger::default()),
+        ];
+        for mut player in players {
+            for consents in [(true, false), (false, false), (false, true)] {
+                player.memorize_last_game(consents, (1, 1));
+            }
+            assert_eq!(player.cooperation_history(), [true, false, false]);
+            assert_eq!(player.opponent_history(), [false, false, true]);
+            player.forget_games();
+            assert!(player.cooperation_history().is_empty());
+            assert!(player.opponent_history().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_all_cooperate() {
+        all_types(
+            &mut AllCooperate::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), true),
+                ((false, false), true),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_all_cheat() {
+        all_types(
+            &mut AllCheat::default(),
+            false,
+            &[
+                ((true, true), false),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_grudger() {
+        all_types(
+            &mut Grudger::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), false),
+                ((true, true), false),
+            ],
+        );
+
+        all_types(
+            &mut Grudger::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, true), true),
+                ((false, true), true),
+                ((true, true), true),
+                ((true, false), false),
+                ((true, true), false),
+                ((true, true), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_detective() {
+        all_types(
+            &mut Detective::default(),
+            true,
+            &[
+                // default initial strat
+                ((false, true), false),
+                ((false, false), true),
+                ((false, true), true),
+                // copycat, coz been cheated
+                ((false, false), false),
+                ((false, false), false),
+                ((false, false), false),
+                ((false, true), true),
+                ((false, true), true),
+                ((false, false), false),
+                ((true, false), false),
+                ((true, true), true),
+            ],
+        );
+
+        all_types(
+            &mut Detective::default(),
+            true,
+            &[
+                // default initial strat
+                ((false, true), false),
+                ((false, true), true),
+                ((false, true), true),
+                // cheater, coz not been cheated
+                ((false, true), false),
+                ((false, true), false),
+                ((false, false), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, false), false),
+                ((true, false), false),
+                ((true, true), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_kindcopycat() {
+        all_types(
+            &mut KindCopyCat::default(),
+            true,
+            &[
+                ((false, true), true),
+                ((true, true), true),
+                ((true, false), true),
+                ((false, true), true),
+                ((true, false), true),
+                ((false, true), true),
+                ((true, false), true),
+                ((false, false), false),
+                ((false, true), true),
+                ((false, false), true),
+                ((true, false), false),
+                ((true, false), false),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_simpleton() {
+        all_types(
+            &mut Simpleton::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), false),
+                ((false, false), true),
+                ((true, false), false),
+                ((false, true), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_bully() {
+        all_types(
+            &mut Bully::default(),
+            false,
+            &[
+                ((false, true), false),
+                ((false, false), true),
+                ((true, false), true),
+                ((true, true), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_firm_but_fair() {
+        all_types(
+            &mut FirmButFair::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), true),
+                ((true, false), false),
+                ((false, false), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_champion() {
+        all_types(
+            &mut Champion::new(10),
+            true,
+            &[
+                ((true, false), false),
+                ((false, true), false),
+                ((false, true), true),
+                ((true, false), false),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_endgame_defector() {
+        let mut player = EndgameDefector::new(2);
+        for (current, reaction) in [(0, true), (7, true), (8, false), (9, false)] {
+            PlayerTrait::<usize>::observe_round_context(&mut player, current, Some(10));
+            assert_eq!(PlayerTrait::<usize>::cooperation_consent(&player), reaction);
+        }
+
+        PlayerTrait::<usize>::observe_round_context(&mut player, 9, None);
+        assert!(PlayerTrait::<usize>::cooperation_consent(&player));
+    }
+
+    #[test]
+    fn test_expiring_grudger() {
+        all_types(
+            &mut ExpiringGrudger::new(3),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), false),
+                ((false, false), false),
+                ((false, true), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_nydegger() {
+        all_types(
+            &mut Nydegger::default(),
+            true,
+            &[
+                ((true, false), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, false), true),
+                ((true, true), true),
+                ((true, false), true),
+                ((true, true), true),
+                ((true, false), true),
+            ],
+        );
+
+        all_types(
+            &mut Nydegger::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, true), true),
+                ((true, false), true),
+                ((true, true), true),
+                ((false, false), true),
+                ((true, false), true),
+                ((false, true), true),
+                ((false, false), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_win_shift_lose_stay() {
+        // rewards never drop so it switches after the first round
+        all_types(
+            &mut WinShiftLoseStay::default(),
+            false,
+            &[
+                ((false, true), false),
+                ((false, true), true),
+                ((true, true), false),
+                ((false, false), true),
+            ],
+        );
+
+        let mut player = WinShiftLoseStay::new(true);
+        player.memorize_last_game((true, true), (2, 2));
+        player.memorize_last_game((true, false), (1, 3));
+        assert!(PlayerTrait::<usize>::cooperation_consent(&player));
+        player.forget_games();
+        player.memorize_last_game((true, true), (2, 2));
+        assert!(player.cooperation_consent());
+    }
+
+    #[test]
+    fn test_nameable() {
+        let players: Vec<Box<dyn Nameable>> = vec![
+            Box::new(CopyCat::default()),
+            Box::new(AllCooperate),
+            Box::new(AllCheat),
+            Box::new(Grudger::default()),
+            Box::new(Detective::default()),
+            Box::new(KindCopyCat::default()),
+            Box::new(Simpleton::default()),
+            Box::new(Bully::default()),
+            Box::new(Opportunist::<usize>::default()),
+            Box::new(WinShiftLoseStay::<usize>::default()),
+            Box::new(FirmButFair::default()),
+            Box::new(Champion::new(200)),
+            Box::new(EndgameDefector::default()),
+            Box::new(Nydegger::default()),
+            Box::new(ThresholdDefector::<usize>::default()),
+            Box::new(ExpiringGrudger::new(1)),
+            Box::new(Punisher::default()),
+            Box::new(SoftGrudger::default()),
+            Box::new(AdaptivePavlov::default()),
+            Box::new(BuiltinPlayer::Simpleton(Default::default())),
+            Box::new(GroupAdapter(AllCheat)),
+            Box::new(BinaryPlayer(AllCheat)),
+        ];
+        for player in players {
+            assert!(!player.label().is_empty());
+        }
+        assert_eq!(
+            BuiltinPlayer::Grudger(Default::default()).label(),
+            "Grudger"
+        );
+        assert_eq!(GroupAdapter(CopyCat::default()).label(), "CopyCat");
+        assert_eq!(Nameable::label(&KindCopyCat::new(3)), "KindCopyCat");
+        assert_eq!(
+            Nameable::label(&ThresholdDefector::<usize>::default()),
+            "ThresholdDefector"
+        );
+    }
+
+    #[test]
+    fn test_resettable() {
+        let mut player: Box<dyn PlayerTrait<isize>> = Box::new(CopyCat::default());
+        player.memorize_last_game((true, false), (-1, 3));
+        assert!(!player.cooperation_consent());
+
+        let resettable: &mut dyn Resettable = &mut player;
+        resettable.reset();
+        assert!(player.cooperation_consent());
+        assert!(player.cooperation_history().is_empty());
+
+        let mut grudger = Grudger::default();
+        PlayerTrait::<isize>::memorize_last_game(&mut grudger, (true, false), (-1, 3));
+        grudger.reset();
+        assert!(PlayerTrait::<isize>::cooperation_consent(&grudger));
+    }
+
+    #[test]
+    fn test_fresh_clone() {
+        let mut detective = Detective::default();
+        for _ in 0..2 {
+            PlayerTrait::<isize>::memorize_last_game(&mut detective, (true, true), (2, 2));
+        }
+
+        let fresh = fresh_clone::<isize>(&detective);
+        assert!(fresh.cooperation_history().is_empty());
+        assert!(fresh.cooperation_consent());
+        // still in the middle of the analysis
+        assert_eq!(
+            PlayerTrait::<isize>::cooperation_history(&detective).len(),
+            2
+        );
+        assert!(PlayerTrait::<isize>::cooperation_consent(&detective));
+    }
+
+    #[test]
+    fn test_strategy_id() {
+        fn boxed(player: impl PlayerTrait<isize> + 'static) -> Box<dyn PlayerTrait<isize>> {
+            Box::new(player)
+        }
+
+        assert!(boxed(CopyCat::default()) == boxed(CopyCat::default()));
+        assert!(boxed(CopyCat::default()) != boxed(KindCopyCat::default()));
+        assert!(boxed(KindCopyCat::new(1)) != boxed(KindCopyCat::new(2)));
+        assert!(boxed(ThresholdDefector::new(5)) != boxed(ThresholdDefector::new(6)));
+        assert!(boxed(ThresholdDefector::new(0)) == boxed(ThresholdDefector::default()));
+
+        let mut copycat = CopyCat::default();
+        PlayerTrait::<isize>::memorize_last_game(&mut copycat, (true, false), (-1, 3));
+        let id = PlayerTrait::<isize>::strategy_id(&copycat);
+        let player = boxed(copycat);
+        assert_eq!(player.strategy_id(), id);
+        assert_eq!(player.clone().strategy_id(), id);
+        assert_eq!(fresh_clone(&*player).strategy_id(), id);
+        assert_eq!(BuiltinPlayer::CopyCat(CopyCat::default()).strategy_id(), id);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(PlayerTrait::<usize>::name(&CopyCat::default()), "CopyCat");
+        assert_eq!(PlayerTrait::<usize>::name(&AllCheat), "AllCheat");
+        assert_eq!(PlayerTrait::<usize>::name(&AllCooperate), "AllCooperate");
+        assert_eq!(PlayerTrait::<usize>::name(&Grudger::default()), "Grudger");
+        assert_eq!(
+            PlayerTrait::<usize>::name(&KindCopyCat::new(2)),
+            "KindCopyCat(2)"
+        );
+        assert_eq!(
+            PlayerTrait::<usize>::name(&SoftGrudger::default()),
+            "SoftGrudger(4, 2)"
+        );
+        assert_eq!(
+            PlayerTrait::<usize>::name(&Detective::default()),
+            "Detective(CDCC)"
+        );
+        assert_eq!(
+            PlayerTrait::<usize>::name(&WinShiftLoseStay::<usize>::new(false)),
+            "WinShiftLoseStay(false)"
+        );
+        assert_eq!(
+            PlayerTrait::<usize>::name(&ThresholdDefector::new(6)),
+            "ThresholdDefector(6)"
+        );
+
+        let boxed: Box<dyn PlayerTrait<usize>> = Box::new(KindCopyCat::default());
+        assert_eq!(boxed.name(), "KindCopyCat(1)");
+        assert_eq!(boxed.clone().name(), "KindCopyCat(1)");
+
+        assert_eq!(
+            BuiltinPlayer::KindCopyCat(KindCopyCat::new(3)).name(),
+            "KindCopyCat(3)"
+        );
+    }
+
+    #[test]
+    fn test_soft_grudger() {
+        all_types(
+            &mut SoftGrudger::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, true), true),
+                ((true, true), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_soft_grudger_offended_in_cycle() {
+        all_types(
+            &mut SoftGrudger::new(1, 1),
+            true,
+            &[
+                ((true, false), false),
+                ((false, false), true), // cheated while punishing
+                ((true, true), false),
+                ((false, true), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_adaptive_pavlov_cooperative() {
+        let mut player = AdaptivePavlov::default();
+        all_types(
+            &mut player,
+            true,
+            &[
+                ((true, true), true),
+                ((true, true), true),
+                ((true, true), true),
+                ((true, true), true),
+                ((true, true), true),
+                ((true, true), true),
+                ((true, false), false), // punish
+                ((false, true), true),
+            ],
+        );
+        assert_eq!(player.mode, AdaptivePavlovMode::Cooperative);
+    }
+
+    #[test]
+    fn test_adaptive_pavlov_defector() {
+        let mut player = AdaptivePavlov::default();
+        all_types(
+            &mut player,
+            true,
+            &[
+                ((true, false), false),
+                ((false, false), false),
+                ((false, false), false),
+                ((false, false), false),
+                ((false, false), false),
+                ((false, false), false),
+                ((false, true), false), // a copycat would cooperate
+                ((false, true), false),
+            ],
+        );
+        assert_eq!(player.mode, AdaptivePavlovMode::Defector);
+    }
+
+    #[test]
+    fn test_adaptive_pavlov_other() {
+        let mut player = AdaptivePavlov::default();
+        all_types(
+            &mut player,
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), true),
+                ((true, false), false),
+                ((false, true), true),
+                ((true, false), false),
+                ((false, false), true), // a copycat would cheat
+                ((true, true), true),
+                ((true, false), false),
+            ],
+        );
+        assert_eq!(player.mode, AdaptivePavlovMode::Other);
+
+        PlayerTrait::<usize>::forget_games(&mut player);
+        assert_eq!(player.mode, AdaptivePavlovMode::Unclassified);
+        assert!(PlayerTrait::<usize>::cooperation_consent(&player));
+    }
+
+    #[test]
+    fn test_punisher() {
+        all_types(
+            &mut Punisher::default(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), true),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, true), false),
+                ((false, true), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_threshold_defector() {
+        let mut player = ThresholdDefector::new(3);
+        for (reward, reaction) in [(1, true), (1, true), (1, false), (-5, false)] {
+            player.memorize_last_game((true, true), (reward, 0));
+            assert_eq!(player.cooperation_consent(), reaction);
+        }
+
+        player.forget_games();
+        assert!(player.cooperation_consent());
+    }
+
+    #[test]
+    fn test_builtin_player() {
+        let mut player = BuiltinPlayer::Simpleton(Default::default());
+        for (consents, reaction) in [
+            ((true, true), true),
+            ((true, false), false),
+            ((false, true), false),
+        ] {
+            player.memorize_last_game(consents, (1, 1));
+            assert_eq!(player.cooperation_consent(), reaction);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_detective_mid_match() {
+        let mut player = Detective::default();
+        for consents in [(true, false), (false, true), (true, true)] {
+            PlayerTrait::<isize>::memorize_last_game(&mut player, consents, (0, 0));
+        }
+
+        let json = serde_json::to_string(&player).unwrap();
+        let mut restored: Detective = serde_json::from_str(&json).unwrap();
+        for consents in [(true, true), (true, false), (false, true), (false, false)] {
+            assert_eq!(
+                PlayerTrait::<isize>::cooperation_consent(&restored),
+                PlayerTrait::<isize>::cooperation_consent(&player),
+            );
+            PlayerTrait::<isize>::memorize_last_game(&mut player, consents, (0, 0));
+            PlayerTrait::<isize>::memorize_last_game(&mut restored, consents, (0, 0));
+        }
+    }
+
+    /// Assert the player restored from JSON in the middle of a match plays the same afterwards.
+    #[cfg(feature = "serde")]
+    fn assert_serde_round_trip<P>(mut player: P)
+    where
+        P: PlayerTrait<isize> + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        for consents in [(true, false), (false, false), (true, true)] {
+            player.memorize_last_game(consents, (0, 0));
+        }
+
+        let json = serde_json::to_string(&player).unwrap();
+        let mut restored: P = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        assert_eq!(restored.cooperation_history(), player.cooperation_history());
+        for consents in [(false, true), (true, false), (true, true), (false, false)] {
+            assert_eq!(restored.cooperation_consent(), player.cooperation_consent());
+            player.memorize_last_game(consents, (0, 0));
+            restored.memorize_last_game(consents, (0, 0));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_players_mid_match() {
+        assert_serde_round_trip(CopyCat::default());
+        assert_serde_round_trip(AllCooperate);
+        assert_serde_round_trip(AllCheat);
+        assert_serde_round_trip(Grudger::default());
+        assert_serde_round_trip(Detective::default());
+        assert_serde_round_trip(KindCopyCat::new(1));
+        assert_serde_round_trip(Simpleton::default());
+        assert_serde_round_trip(Bully::default());
+        assert_serde_round_trip(Opportunist::<isize>::default());
+        assert_serde_round_trip(WinShiftLoseStay::<isize>::default());
+        assert_serde_round_trip(FirmButFair::default());
+        assert_serde_round_trip(Champion::new(10));
+        assert_serde_round_trip(EndgameDefector::default());
+        assert_serde_round_trip(Nydegger::default());
+        assert_serde_round_trip(ThresholdDefector::<isize>::default());
+        assert_serde_round_trip(ExpiringGrudger::new(2));
+        assert_serde_round_trip(Punisher::default());
+        assert_serde_round_trip(SoftGrudger::default());
+        assert_serde_round_trip(AdaptivePavlov::default());
+        assert_serde_round_trip(BuiltinPlayer::Grudger(Default::default()));
+
+        // the identity of the player only comes from its (deserialized) parameters
+        let json = serde_json::to_string(&ThresholdDefector::<isize>::new(5)).unwrap();
+        let restored: ThresholdDefector<isize> =
+            serde_json::from_str(&json.replace(r#""target":5"#, r#""target":6"#)).unwrap();
+        assert_eq!(
+            restored.strategy_id(),
+            PlayerTrait::<isize>::strategy_id(&ThresholdDefector::new(6))
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "rand"))]
+    #[test]
+    fn test_serde_seeded_players_mid_match() {
+        assert_serde_round_trip(SeededRandom::new(3));
+        assert_serde_round_trip(Joss::default().with_seed(3));
+        assert_serde_round_trip(StochasticCopyCat::new(0.5).unwrap().with_seed(3));
+        assert_serde_round_trip(Grofman::default().with_seed(3));
+        assert_serde_round_trip(BuiltinPlayer::Joss(Joss::default().with_seed(3)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_builtin_roster() {
+        let roster = vec![
+            BuiltinPlayer::CopyCat(Default::default()),
+            BuiltinPlayer::AllCooperate(AllCooperate),
+            BuiltinPlayer::AllCheat(AllCheat),
+            BuiltinPlayer::Grudger(Default::default()),
+            BuiltinPlayer::Detective(Detective::new(vec![false, true])),
+            BuiltinPlayer::KindCopyCat(KindCopyCat::new(3)),
+            BuiltinPlayer::Simpleton(Default::default()),
+            BuiltinPlayer::Opportunist(Default::default()),
+            BuiltinPlayer::SoftGrudger(SoftGrudger::new(2, 1)),
+            BuiltinPlayer::AdaptivePavlov(Default::default()),
+        ];
+
+        let json = serde_json::to_string(&roster).unwrap();
+        let restored: Vec<BuiltinPlayer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, serde_json::to_string(&restored).unwrap());
+        for (player, restored) in roster.iter().zip(restored.iter()) {
+            assert_eq!(player.cooperation_consent(), restored.cooperation_consent());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_roster_round_trip() {
+        let mut grudger = Grudger::default();
+        PlayerTrait::<isize>::memorize_last_game(&mut grudger, (true, false), (-1, 3));
+        let roster: Vec<Box<dyn SerializablePlayer>> = vec![
+            Box::new(grudger),
+            Box::new(CopyCat::default()),
+            Box::new(Detective::new(vec![false, true])),
+            Box::new(KindCopyCat::new(3)),
+            Box::new(WinShiftLoseStay::<isize>::default()),
+            Box::new(ThresholdDefector::<isize>::default()),
+            Box::new(ExpiringGrudger::new(2)),
+        ];
+
+        let json = serialize_roster(&roster).unwrap();
+        let restored = deserialize_roster(&json).unwrap();
+        assert_eq!(json, serialize_roster(&restored).unwrap());
+        // still grudging after reload
+        assert!(!restored[0].cooperation_consent());
+        for (player, restored) in roster.iter().zip(restored.iter()) {
+            assert_eq!(player.type_name(), restored.type_name());
+            assert_eq!(player.cooperation_consent(), restored.cooperation_consent());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_roster_unknown_type() {
+        let Err(error) = deserialize_roster(r#"[{"type": "Stranger", "state": null}]"#) else {
+            panic!("an unknown type is loaded");
+        };
+        assert!(matches!(&error, RosterError::UnknownType(name) if name == "Stranger"));
+        assert_eq!(
+            error.to_string(),
+            "The player type \"Stranger\" is not known."
+        );
+        assert!(matches!(
+            deserialize_roster("not json"),
+            Err(RosterError::Json(_))
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    /// Get the moves of the player against an alternating opponent.
+    fn random_moves(player: &mut dyn PlayerTrait<usize>, rounds: usize) -> Vec<bool> {
+        (0..rounds)
+            .map(|round| {
+                let consent = PlayerTrait::<usize>::cooperation_consent(player);
+                player.memorize_last_game((consent, round % 2 == 0), (1, 1));
+                consent
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_seeded_random_same_seed() {
+        let moves = random_moves(&mut SeededRandom::new(42), 100);
+        assert_eq!(moves, random_moves(&mut SeededRandom::new(42), 100));
+        assert!(moves.contains(&true) && moves.contains(&false));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_randomized_players_with_seed() {
+        assert_eq!(
+            random_moves(&mut Random::with_seed(3), 100),
+            random_moves(&mut SeededRandom::new(3), 100)
+        );
+        // the seeded randomized players by index
+        fn build(i: usize) -> Box<dyn PlayerTrait<usize>> {
+            match i {
+                0 => Box::new(Joss::new(0.5).unwrap().with_seed(3)),
+                1 => Box::new(StochasticCopyCat::new(0.5).unwrap().with_seed(3)),
+                _ => Box::new(Grofman::new(0.5).unwrap().with_seed(3)),
+            }
+        }
+        for i in 0..3 {
+            let mut player = build(i);
+            let moves = random_moves(&mut *player, 100);
+            assert_eq!(moves, random_moves(&mut *build(i), 100));
+            assert!(moves.contains(&true) && moves.contains(&false));
+
+            // reseeding restarts the rolls of a copy
+            let mut copy = dyn_clone::clone_box(&*player);
+            player.reseed(9);
+            copy.reseed(9);
+            assert_eq!(
+                random_moves(&mut *player, 100),
+                random_moves(&mut *copy, 100)
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_from() {
+        let mut state = 11;
+        let rolls: Vec<bool> = (0..10_000).map(|_| roll_from(0.3, &mut state)).collect();
+        let hits = rolls.iter().filter(|&&hit| hit).count();
+        assert!((2_800..3_200).contains(&hits), "{} hits of 10000", hits);
+
+        let mut replay = 11;
+        assert!(rolls.iter().all(|&hit| roll_from(0.3, &mut replay) == hit));
+        assert!((0..100).all(|_| !roll_from(0.0, &mut state) && roll_from(1.0, &mut state)));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_seeded_random_clone() {
+        let mut player = SeededRandom::new(7);
+        random_moves(&mut player, 10);
+        let mut cloned = player.clone();
+        assert_eq!(
+            random_moves(&mut player, 100),
+            random_moves(&mut cloned, 100)
+        );
+    }
+
+    #[test]
+    fn test_opportunist() {
+        let mut player = Opportunist::<isize>::default();
+        for (rewards, reaction) in [
+            ((0, 0), true),
+            ((3, 1), false),
+            ((-2, 0), true),
+            ((0, 1), true),
+            ((2, 0), false),
+        ] {
+            player.memorize_last_game((true, true), rewards);
+            assert_eq!(player.cooperation_consent(), reaction);
+        }
+
+        player.forget_games();
+        assert!(player.cooperation_consent());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_joss_bounds() {
+        assert!(Joss::new(-0.1).is_err());
+        assert!(Joss::new(1.1).is_err());
+        assert!(Joss::new(0.5).is_ok());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_joss_as_copycat() {
+        all_types(
+            &mut Joss::new(0.0).unwrap(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_joss_as_allcheat() {
+        all_types(
+            &mut Joss::new(1.0).unwrap(),
+            false,
+            &[
+                ((true, true), false),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), false),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_joss_sneak_rate() {
+        let rounds = 10_000;
+        let mut player = Joss::default();
+        let mut cheats = 0;
+        for _ in 0..rounds {
+            if !PlayerTrait::<usize>::cooperation_consent(&player) {
+                cheats += 1;
+            }
+            player.memorize_last_game((true, true), (1, 1));
+        }
+        let rate = cheats as f64 / rounds as f64;
+        assert!((0.08..=0.12).contains(&rate), "sneak rate was {rate}");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_stochastic_copycat_bounds() {
+        assert!(StochasticCopyCat::new(-0.1).is_err());
+        assert!(StochasticCopyCat::new(1.1).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_stochastic_copycat_as_copycat() {
+        all_types(
+            &mut StochasticCopyCat::new(1.0).unwrap(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, false), false),
+                ((false, true), true),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_stochastic_copycat_locked() {
+        all_types(
+            &mut StochasticCopyCat::new(0.0).unwrap(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), true),
+                ((true, false), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_stochastic_copycat_imitation_rate() {
+        let rounds = 10_000;
+        let mut player = StochasticCopyCat::new(0.5).unwrap();
+        let mut imitations = 0;
+        for _ in 0..rounds {
+            // the opponent always plays the opposite, so imitating means changing the move
+            let own = PlayerTrait::<usize>::cooperation_consent(&player);
+            player.memorize_last_game((own, !own), (1, 1));
+            if PlayerTrait::<usize>::cooperation_consent(&player) != own {
+                imitations += 1;
+            }
+        }
+        let rate = imitations as f64 / rounds as f64;
+        assert!((0.45..=0.55).contains(&rate), "imitation rate was {rate}");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_grofman_bounds() {
+        assert!(Grofman::new(-0.1).is_err());
+        assert!(Grofman::new(1.1).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_grofman_never_forgiving() {
+        all_types(
+            &mut Grofman::new(0.0).unwrap(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), false),
+                ((false, true), false),
+                ((false, false), true),
+                ((true, true), true),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_grofman_always_forgiving() {
+        all_types(
+            &mut Grofman::new(1.0).unwrap(),
+            true,
+            &[
+                ((true, true), true),
+                ((true, false), true),
+                ((false, true), true),
+                ((false, false), true),
+            ],
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_grofman_forgiveness_rate() {
+        let rounds = 10_000;
+        let mut player = Grofman::default();
+        let mut cooperations = 0;
+        for _ in 0..rounds {
+            player.memorize_last_game((true, false), (1, 1));
+            if PlayerTrait::<usize>::cooperation_consent(&player) {
+                cooperations += 1;
+            }
+        }
+        let rate = cooperations as f64 / rounds as f64;
+        assert!((0.26..=0.31).contains(&rate), "cooperation rate was {rate}");
     }
 }
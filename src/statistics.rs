@@ -0,0 +1,251 @@
+//! Summaries of scores and moves independent of the matches that produced them.
+//!
+//! All the functions return 0.0 for empty inputs.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::matches::GenerationSnapshot;
+
+/// Get the arithmetic mean of the scores.
+pub fn mean(scores: &[isize]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.iter().map(|&s| s as f64).sum::<f64>() / scores.len() as f64
+}
+
+/// Get the (population) standard deviation of the scores.
+pub fn std_dev(scores: &[isize]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    let mean = mean(scores);
+    let variance = scores
+        .iter()
+        .map(|&s| (s as f64 - mean).powi(2))
+        .sum::<f64>()
+        / scores.len() as f64;
+    variance.sqrt()
+}
+
+/// Get the middle score (the mean of the two middle scores for even lengths).
+pub fn median(scores: &[isize]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0
+    } else {
+        sorted[middle] as f64
+    }
+}
+
+/// Get the fraction of the moves which are cooperations (`true`).
+pub fn cooperation_rate(history: &[bool]) -> f64 {
+    if history.is_empty() {
+        return 0.0;
+    }
+    history.iter().filter(|&&c| c).count() as f64 / history.len() as f64
+}
+
+/// Get the Gini coefficient of the scores, from 0.0 (all equal) to near 1.0 (one takes all).
+///
+/// The coefficient is only meaningful for non-negative scores and is 0.0 if they sum to zero.
+pub fn gini_coefficient(scores: &[isize]) -> f64 {
+    let total = scores.iter().map(|&s| s as f64).sum::<f64>();
+    if scores.is_empty() || total == 0.0 {
+        return 0.0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len() as f64;
+    // (2 * sum of rank * score) / (n * total) - (n + 1) / n with ranks from 1
+    let weighted = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &s)| (i + 1) as f64 * s as f64)
+        .sum::<f64>();
+    2.0 * weighted / (n * total) - (n + 1.0) / n
+}
+
+/// Get the probability of each type present in the population (ordered by type).
+fn type_probabilities(population: &[usize]) -> Vec<f64> {
+    let mut counts = BTreeMap::new();
+    for &player_type in population {
+        *counts.entry(player_type).or_insert(0usize) += 1;
+    }
+    let total = population.len() as f64;
+    counts
+        .into_values()
+        .map(|count| count as f64 / total)
+        .collect()
+}
+
+/// Get the Shannon entropy (in bits) of the types of the population.
+///
+/// It is 0.0 for a single type and at most `log2(k)` for `k` types (evenly present).
+pub fn population_entropy(population: &[usize]) -> f64 {
+    -type_probabilities(population)
+        .into_iter()
+        .map(|p| p * p.log2())
+        .sum::<f64>()
+}
+
+/// Get the entropy of the population divided by its maximum for the types present (from 0.0 to
+/// 1.0).
+///
+/// It is 0.0 for a single type since no mixing is possible.
+pub fn normalised_population_entropy(population: &[usize]) -> f64 {
+    let types = type_probabilities(population).len();
+    if types < 2 {
+        return 0.0;
+    }
+    population_entropy(population) / (types as f64).log2()
+}
+
+/// Get the fraction of the matchups between each two types (`(a, b)`) which `a` won.
+///
+/// The history holds the types and the scores of both sides of each matchup (see
+/// [`crate::matches::Arena::with_matchup_recording`]). A win is a strictly higher score and a
+/// matchup of the same type counts once for each side.
+pub fn pairwise_win_rates<T: PartialOrd>(
+    history: &[(usize, usize, T, T)],
+) -> HashMap<(usize, usize), f64> {
+    // (wins, matchups) of each ordered pair
+    let mut counts: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    for (type_i, type_j, score_i, score_j) in history {
+        for (a, b, won) in [
+            (*type_i, *type_j, score_i > score_j),
+            (*type_j, *type_i, score_j > score_i),
+        ] {
+            let count = counts.entry((a, b)).or_default();
+            count.0 += won as usize;
+            count.1 += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|(pair, (wins, matchups))| (pair, wins as f64 / matchups as f64))
+        .collect()
+}
+
+/// Get the average score of each type in each generation (0.0 where the type did not play).
+///
+/// The series hold one average for each snapshot and cover every type seen in the played or
+/// the next populations (see [`crate::matches::Arena::run_for_generations`]).
+pub fn score_trajectory(snapshots: &[GenerationSnapshot<isize>]) -> HashMap<usize, Vec<f64>> {
+    let mut trajectory: HashMap<usize, Vec<f64>> = snapshots
+        .iter()
+        .flat_map(|s| s.players.iter().chain(s.population.iter()))
+        .map(|&player_type| (player_type, vec![0.0; snapshots.len()]))
+        .collect();
+    for (generation, snapshot) in snapshots.iter().enumerate() {
+        // (total, count) of each type
+        let mut totals: BTreeMap<usize, (isize, usize)> = BTreeMap::new();
+        for (&player_type, &score) in snapshot.players.iter().zip(snapshot.scores.iter()) {
+            let total = totals.entry(player_type).or_default();
+            total.0 += score;
+            total.1 += 1;
+        }
+        for (player_type, (total, count)) in totals {
+            if let Some(series) = trajectory.get_mut(&player_type) {
+                series[generation] = total as f64 / count as f64;
+            }
+        }
+    }
+    trajectory
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_and_std_dev() {
+        assert_eq!(mean(&[2, 4, 4, 4, 5, 5, 7, 9]), 5.0);
+        assert_eq!(std_dev(&[2, 4, 4, 4, 5, 5, 7, 9]), 2.0);
+        assert_eq!(std_dev(&[3; 4]), 0.0);
+        assert_eq!(mean(&[]), 0.0);
+        assert_eq!(std_dev(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[7, -1]), 3.0);
+        assert_eq!(median(&[5, 1, 3]), 3.0);
+        assert_eq!(median(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_cooperation_rate() {
+        assert_eq!(cooperation_rate(&[false; 5]), 0.0);
+        assert_eq!(cooperation_rate(&[true, false, true, true]), 0.75);
+        assert_eq!(cooperation_rate(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_gini_coefficient() {
+        assert_eq!(gini_coefficient(&[5; 10]), 0.0);
+        assert!((gini_coefficient(&[0, 0, 0, 8]) - 0.75).abs() < f64::EPSILON);
+        assert!((gini_coefficient(&[1, 3]) - 0.25).abs() < f64::EPSILON);
+        assert_eq!(gini_coefficient(&[0; 3]), 0.0);
+        assert_eq!(gini_coefficient(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_population_entropy() {
+        assert_eq!(population_entropy(&[3; 5]), 0.0);
+        assert_eq!(normalised_population_entropy(&[3; 5]), 0.0);
+        assert_eq!(population_entropy(&[0, 1, 1, 0]), 1.0);
+        assert_eq!(normalised_population_entropy(&[0, 1, 1, 0]), 1.0);
+        assert_eq!(population_entropy(&[0, 1, 2, 3]), 2.0);
+        assert_eq!(normalised_population_entropy(&[0, 1, 2, 3]), 1.0);
+        assert!(normalised_population_entropy(&[0, 0, 0, 1]) < 1.0);
+        assert_eq!(population_entropy(&[]), 0.0);
+        assert_eq!(normalised_population_entropy(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_pairwise_win_rates() {
+        let history = [
+            (0, 1, 3, 1),
+            (1, 0, 5, 2),
+            (0, 1, 4, 0),
+            (0, 1, 2, 2),
+            (2, 2, 1, 0),
+        ];
+        let rates = pairwise_win_rates(&history);
+        assert_eq!(rates.len(), 3);
+        assert_eq!(rates[&(0, 1)], 0.5);
+        assert_eq!(rates[&(1, 0)], 0.25);
+        assert_eq!(rates[&(2, 2)], 0.5);
+        assert!(pairwise_win_rates::<isize>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_score_trajectory() {
+        let snapshot = |generation_index, players: &[usize], scores: &[isize], next: &[usize]| {
+            GenerationSnapshot {
+                generation_index,
+                players: players.to_vec(),
+                population: next.to_vec(),
+                scores: scores.to_vec(),
+            }
+        };
+        // the first type takes over the second
+        let snapshots = [
+            snapshot(0, &[0, 1, 1, 1], &[10, 4, 6, 2], &[0, 0, 1, 1]),
+            snapshot(1, &[0, 0, 1, 1], &[12, 8, 3, 3], &[0, 0, 0, 1]),
+            snapshot(2, &[0, 0, 0, 1], &[9, 9, 9, 1], &[0, 0, 0, 0]),
+            snapshot(3, &[0, 0, 0, 0], &[7, 7, 7, 7], &[0, 0, 0, 0]),
+        ];
+        let trajectory = score_trajectory(&snapshots);
+        assert_eq!(trajectory.len(), 2);
+        assert_eq!(trajectory[&0], [10.0, 10.0, 9.0, 7.0]);
+        assert_eq!(trajectory[&1], [4.0, 3.0, 1.0, 0.0]);
+        assert!(score_trajectory(&[]).is_empty());
+    }
+}
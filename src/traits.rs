@@ -1,5 +1,16 @@
 //! Holds all the traits for this crate.
 
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    errors::PlayerError,
+    matches::{MatchResult, RoundOutcome},
+};
+
 use auto_impl::auto_impl;
 use dyn_clone::DynClone;
 
@@ -24,6 +35,78 @@ pub trait PlayerTrait<T>: DynClone {
 
     /// Reset the memory.
     fn forget_games(&mut self) {}
+
+    /// Get told which round (starting from 0) is about to be played out of how many (if known).
+    ///
+    /// Coordinators call this before each round they know the context of (e.g.
+    /// [`MatchTrait::play_for_rounds`]) so players may ignore it.
+    #[allow(unused_variables)]
+    fn observe_round_context(&mut self, round_index: usize, planned_rounds: Option<usize>) {}
+
+    /// Get told a match against a new opponent is about to start.
+    ///
+    /// Unlike [`Self::forget_games`], this is not meant to reset the player but to let it tell
+    /// the opponents apart (e.g. for learning across matches).
+    ///
+    /// Arenas build a fresh player for every match by default so only the arenas keeping the
+    /// players (see [`crate::matches::MemoryPolicy`]) let it learn across matches.
+    fn notify_match_start(&mut self) {}
+
+    /// Get told the match is over with the final scores (own score first).
+    ///
+    /// Like [`Self::notify_match_start`], the player only outlives the match in the arenas
+    /// keeping the players.
+    #[allow(unused_variables)]
+    fn notify_match_end(&mut self, final_scores: (T, T)) {}
+
+    /// Reset the random generator of the player (if any) with the given seed.
+    #[allow(unused_variables)]
+    fn reseed(&mut self, seed: u64) {}
+
+    /// Whether the moves of the player only depend on the games so far (no randomness).
+    ///
+    /// Arenas play a single match for each two types of deterministic prototypes and reuse its
+    /// scores for the other pairings of the same types.
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    /// Determine the chance of cooperating in the next round (`0.0..=1.0`).
+    ///
+    /// Only used by coordinators playing the expected outputs instead of the consents (see
+    /// [`ExpectedValueMachineTrait`]). Defaults to the certainty of [`Self::cooperation_consent`].
+    fn cooperation_probability(&self) -> f64 {
+        if self.cooperation_consent() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// The name of the strategy (including its parameters, if any).
+    fn name(&self) -> Cow<'_, str> {
+        "Unknown".into()
+    }
+
+    /// Identify the strategy of the player, telling apart the same types with other parameters.
+    ///
+    /// Defaults to the type of the player with no parameters.
+    fn strategy_id(&self) -> StrategyId {
+        StrategyId::new(std::any::type_name::<Self>(), ())
+    }
+
+    /// Get the past consents of the player itself (since the last [`Self::forget_games`]).
+    ///
+    /// Only for analysis so the players not keeping their history return an empty slice.
+    fn cooperation_history(&self) -> &[bool] {
+        &[]
+    }
+
+    /// Get the past consents of the opponent as seen by the player (see
+    /// [`Self::cooperation_history`]).
+    fn opponent_history(&self) -> &[bool] {
+        &[]
+    }
 }
 
 impl<T> Clone for Box<dyn PlayerTrait<T>>
@@ -35,6 +118,360 @@ where
     }
 }
 
+/// Players are equal if they play the same strategy (see [`PlayerTrait::strategy_id`]).
+impl<T> PartialEq for Box<dyn PlayerTrait<T>> {
+    fn eq(&self, other: &Self) -> bool {
+        self.strategy_id() == other.strategy_id()
+    }
+}
+
+/// Identifies the strategy of a player regardless of its memory (see [`PlayerTrait::strategy_id`]).
+///
+/// Players of the same type with different parameters (e.g. `KindCopyCat::new(1)` and
+/// `KindCopyCat::new(2)`) have different fingerprints. The fingerprints are stable between runs
+/// (but not between platforms of different pointer widths).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StrategyId {
+    /// The name of the type of the player.
+    pub type_name: &'static str,
+    /// The hash of the parameters of the player.
+    fingerprint: u64,
+}
+
+impl StrategyId {
+    pub fn new(type_name: &'static str, params: impl Hash) -> Self {
+        let mut hasher = FnvHasher::default();
+        params.hash(&mut hasher);
+        Self {
+            type_name,
+            fingerprint: hasher.finish(),
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash which (unlike [`std::collections::hash_map::DefaultHasher`]) is the
+/// same in every run.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+/// Get a copy of the player which has forgotten all its games (the player itself is not changed).
+///
+/// This is how the prototypes of the arenas are built for each match (see [`PlayerFactory`] to
+/// build the players otherwise).
+pub fn fresh_clone<T>(player: &(dyn PlayerTrait<T> + 'static)) -> Box<dyn PlayerTrait<T>> {
+    let mut fresh = dyn_clone::clone_box(player);
+    fresh.forget_games();
+    fresh
+}
+
+/// Constructs fresh players of a type (e.g. for each match of [`crate::matches::Arena`]).
+///
+/// Implemented for closures returning players and for prototype players (cloned on build). The
+/// players built are boxed as `P` (see [`ArenaPlayer`]).
+pub trait PlayerFactory<T, P: ?Sized = dyn PlayerTrait<T>> {
+    /// Get a new player with no memory of any games.
+    fn build(&self) -> Box<P>;
+
+    /// The name of the players built (see [`PlayerTrait::name`]).
+    ///
+    /// Builds a player by default (which may have side effects, e.g. for counting factories) so
+    /// arenas only ask for it once at construction.
+    fn player_name(&self) -> Cow<'_, str>
+    where
+        P: PlayerTrait<T>,
+    {
+        self.build().name().into_owned().into()
+    }
+
+    /// Whether the players built are deterministic (see [`PlayerTrait::is_deterministic`]).
+    ///
+    /// Defaults to false so the players are never built to check it. Arenas play a single match
+    /// for each two types of deterministic factories.
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+impl<T, F> PlayerFactory<T> for F
+where
+    F: Fn() -> Box<dyn PlayerTrait<T>>,
+{
+    fn build(&self) -> Box<dyn PlayerTrait<T>> {
+        self()
+    }
+}
+
+impl<T, F> PlayerFactory<T, dyn SyncPlayerTrait<T>> for F
+where
+    F: Fn() -> Box<dyn SyncPlayerTrait<T>>,
+{
+    fn build(&self) -> Box<dyn SyncPlayerTrait<T>> {
+        self()
+    }
+}
+
+impl<T> PlayerFactory<T> for Box<dyn PlayerTrait<T>> {
+    fn build(&self) -> Box<dyn PlayerTrait<T>> {
+        fresh_clone(&**self)
+    }
+
+    fn player_name(&self) -> Cow<'_, str> {
+        self.name()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        PlayerTrait::is_deterministic(&**self)
+    }
+}
+
+impl<T> PlayerFactory<T, dyn SyncPlayerTrait<T>> for Box<dyn SyncPlayerTrait<T>> {
+    fn build(&self) -> Box<dyn SyncPlayerTrait<T>> {
+        let mut fresh = self.clone();
+        fresh.forget_games();
+        fresh
+    }
+
+    fn player_name(&self) -> Cow<'_, str> {
+        self.name()
+    }
+
+    fn is_deterministic(&self) -> bool {
+        PlayerTrait::is_deterministic(&**self)
+    }
+}
+
+/// A [`PlayerTrait`] which is always thread-safe.
+///
+/// Implemented for all the thread-safe players (e.g. all the built-in ones).
+pub trait SyncPlayerTrait<T>: PlayerTrait<T> + Send + Sync {}
+
+impl<T, P: PlayerTrait<T> + Send + Sync + ?Sized> SyncPlayerTrait<T> for P {}
+
+impl<T> Clone for Box<dyn SyncPlayerTrait<T>>
+where
+    dyn SyncPlayerTrait<T>: DynClone,
+{
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// The boxed players of an [`crate::matches::Arena`] (either `dyn PlayerTrait<T>` or the
+/// thread-safe `dyn SyncPlayerTrait<T>`) with the matching factories and observer.
+pub trait ArenaPlayer<T>: PlayerTrait<T> {
+    /// The factories building the players.
+    type Factory: ?Sized + PlayerFactory<T, Self>;
+    /// The observer of the arena.
+    type Observer: ?Sized + MatchObserver<T>;
+
+    /// Get the factory cloning (and forgetting) the given prototype for each build.
+    fn prototype_factory(prototype: Box<Self>) -> Box<Self::Factory>
+    where
+        T: 'static;
+}
+
+impl<T> ArenaPlayer<T> for dyn PlayerTrait<T> {
+    type Factory = dyn PlayerFactory<T>;
+    type Observer = dyn MatchObserver<T>;
+
+    fn prototype_factory(prototype: Box<Self>) -> Box<Self::Factory>
+    where
+        T: 'static,
+    {
+        Box::new(prototype)
+    }
+}
+
+impl<T> ArenaPlayer<T> for dyn SyncPlayerTrait<T> {
+    type Factory = dyn PlayerFactory<T, Self> + Send + Sync;
+    type Observer = dyn MatchObserver<T> + Send + Sync;
+
+    fn prototype_factory(prototype: Box<Self>) -> Box<Self::Factory>
+    where
+        T: 'static,
+    {
+        Box::new(prototype)
+    }
+}
+
+/// Gives a human-readable label to an entity (e.g. players, machines and strategies) for reports.
+pub trait Nameable {
+    /// The name of the entity (without parameters, see [`PlayerTrait::name`] for that).
+    fn label(&self) -> &str;
+
+    /// A short description of the entity (empty if not given).
+    fn description(&self) -> &str {
+        ""
+    }
+}
+
+/// A player which can be saved in a roster of different types of players (requires "serde").
+///
+/// See [`crate::players::serialize_roster`].
+#[cfg(feature = "serde")]
+pub trait SerializablePlayer: PlayerTrait<isize> {
+    /// The name of the type of the player in the registry of the roster.
+    fn type_name(&self) -> &'static str;
+
+    /// Get the current state (including the memory) of the player.
+    fn state(&self) -> serde_json::Result<serde_json::Value>;
+}
+
+#[cfg(feature = "serde")]
+impl Clone for Box<dyn SerializablePlayer> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Restores an entity to its initial state (e.g. the memory of players or scores of machines).
+pub trait Resettable {
+    /// Reset the state (e.g. [`PlayerTrait::forget_games`] or [`MachineTrait::reset_scores`]).
+    fn reset(&mut self);
+}
+
+impl<T> Resettable for Box<dyn PlayerTrait<T>> {
+    fn reset(&mut self) {
+        self.forget_games()
+    }
+}
+
+/// A player which may fail to play (e.g. decisions coming from a subprocess or the network).
+///
+/// Every [`PlayerTrait`] is a [`TryPlayerTrait`] which never fails.
+pub trait TryPlayerTrait<T> {
+    /// Same as [`PlayerTrait::cooperation_consent`] but fallible.
+    fn try_cooperation_consent(&self) -> Result<bool, PlayerError>;
+
+    /// Same as [`PlayerTrait::memorize_last_game`] but fallible.
+    #[allow(unused_variables)]
+    fn try_memorize_last_game(
+        &mut self,
+        last_consents: (bool, bool),
+        last_rewards: (T, T),
+    ) -> Result<(), PlayerError> {
+        Ok(())
+    }
+
+    /// Same as [`PlayerTrait::observe_round_context`] but fallible.
+    #[allow(unused_variables)]
+    fn try_observe_round_context(
+        &mut self,
+        round_index: usize,
+        planned_rounds: Option<usize>,
+    ) -> Result<(), PlayerError> {
+        Ok(())
+    }
+}
+
+impl<T, P: PlayerTrait<T> + ?Sized> TryPlayerTrait<T> for P {
+    fn try_cooperation_consent(&self) -> Result<bool, PlayerError> {
+        Ok(self.cooperation_consent())
+    }
+
+    fn try_memorize_last_game(
+        &mut self,
+        last_consents: (bool, bool),
+        last_rewards: (T, T),
+    ) -> Result<(), PlayerError> {
+        self.memorize_last_game(last_consents, last_rewards);
+        Ok(())
+    }
+
+    fn try_observe_round_context(
+        &mut self,
+        round_index: usize,
+        planned_rounds: Option<usize>,
+    ) -> Result<(), PlayerError> {
+        self.observe_round_context(round_index, planned_rounds);
+        Ok(())
+    }
+}
+
+/// Determines the behaviour of a player in games of more than two players.
+///
+/// Any [`PlayerTrait`] can play in groups using [`crate::players::GroupAdapter`].
+pub trait GroupPlayerTrait<T>: DynClone {
+    /// Determine whether the player should cooperate or not (player's answer to the next round).
+    fn cooperation_consent(&self) -> bool;
+
+    /// Add the last game to the memory given how many of the other players cooperated.
+    #[allow(unused_variables)]
+    fn memorize_last_group_game(
+        &mut self,
+        own_consent: bool,
+        other_cooperators: usize,
+        others: usize,
+        last_reward: T,
+    ) {
+    }
+
+    /// Reset the memory.
+    fn forget_games(&mut self) {}
+}
+
+impl<T> Clone for Box<dyn GroupPlayerTrait<T>>
+where
+    dyn GroupPlayerTrait<T>: DynClone,
+{
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// A move of a player in games with any number of moves (see [`crate::moves`]).
+///
+/// Implemented for `bool` (cooperation first) so the binary games can be played as well.
+pub trait Move: Copy + Eq + 'static {
+    /// All the possible moves in order of the rows (and columns) of [`crate::moves::NMatrix`].
+    const ALL: &'static [Self];
+
+    /// Get the place of the move in [`Self::ALL`].
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|m| m == self)
+            .expect("every move must be in Move::ALL")
+    }
+}
+
+impl Move for bool {
+    const ALL: &'static [Self] = &[true, false];
+}
+
+/// Determines the behaviour of a player in games with any number of moves.
+///
+/// This is [`PlayerTrait`] with [`Move`]s instead of consents (see
+/// [`crate::players::BinaryPlayer`] to use those).
+pub trait NPlayerTrait<T, M: Move>: DynClone {
+    /// Determine the move of the player in the next round.
+    fn next_move(&self) -> M;
+
+    /// Add the last game to the memory to make observations based on that.
+    #[allow(unused_variables)]
+    fn memorize_last_game(&mut self, last_moves: (M, M), last_rewards: (T, T)) {}
+
+    /// Reset the memory.
+    fn forget_games(&mut self) {}
+}
+
 /// A machine receiving inputs from players and putting out the results.
 #[auto_impl(&mut, Box)]
 pub trait MachineTrait<T: Clone> {
@@ -50,23 +487,180 @@ pub trait MachineTrait<T: Clone> {
     /// Add another round of scores the leaderboard.
     fn record_scores(&mut self, last_rewards: (T, T));
 
+    /// Get how many rounds are recorded since the last [`Self::reset_scores`] (if counted).
+    fn round_count(&self) -> usize {
+        0
+    }
+
+    /// Get the consents and the rewards the machine registered in the last recorded round (if
+    /// kept).
+    fn last_round(&self) -> Option<((bool, bool), (T, T))> {
+        None
+    }
+
+    /// Reset the random generator of the machine (if any) with the given seed.
+    #[allow(unused_variables)]
+    fn reseed(&mut self, seed: u64) {}
+
+    /// Whether the rewards of the machine only depend on the consents (no randomness).
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
     /// Play the inputs and get the outputs (mutating scoreboard and recording each result).
     fn play(&mut self, consents: (bool, bool)) -> (T, T) {
         let last_rewards = self.play_off_record(consents);
         self.record_scores(last_rewards.clone());
         last_rewards
     }
+
+    /// Same as [`Self::play`] but also get the consents the machine registered for the players.
+    ///
+    /// The registered consents may differ from the given ones if the machine malfunctions (see
+    /// [`crate::machines`]) so the players must memorize these instead.
+    fn play_registered(&mut self, consents: (bool, bool)) -> ((bool, bool), (T, T)) {
+        (consents, self.play(consents))
+    }
+}
+
+/// A machine shared behind a lock (e.g. between threads).
+impl<T: Clone, M: MachineTrait<T>> MachineTrait<T> for Arc<Mutex<M>> {
+    fn play_off_record(&self, consents: (bool, bool)) -> (T, T) {
+        self.lock()
+            .expect("the machine is poisoned")
+            .play_off_record(consents)
+    }
+
+    fn scores(&self) -> (T, T) {
+        self.lock().expect("the machine is poisoned").scores()
+    }
+
+    fn reset_scores(&mut self) {
+        self.lock().expect("the machine is poisoned").reset_scores()
+    }
+
+    fn record_scores(&mut self, last_rewards: (T, T)) {
+        self.lock()
+            .expect("the machine is poisoned")
+            .record_scores(last_rewards)
+    }
+
+    fn round_count(&self) -> usize {
+        self.lock().expect("the machine is poisoned").round_count()
+    }
+
+    fn last_round(&self) -> Option<((bool, bool), (T, T))> {
+        self.lock().expect("the machine is poisoned").last_round()
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.lock().expect("the machine is poisoned").reseed(seed)
+    }
+
+    fn is_deterministic(&self) -> bool {
+        self.lock()
+            .expect("the machine is poisoned")
+            .is_deterministic()
+    }
+
+    fn play(&mut self, consents: (bool, bool)) -> (T, T) {
+        self.lock().expect("the machine is poisoned").play(consents)
+    }
+
+    fn play_registered(&mut self, consents: (bool, bool)) -> ((bool, bool), (T, T)) {
+        self.lock()
+            .expect("the machine is poisoned")
+            .play_registered(consents)
+    }
+}
+
+/// A machine which can also play the chances of cooperation instead of sampling consents.
+#[auto_impl(&mut, Box)]
+pub trait ExpectedValueMachineTrait<T: Clone>: MachineTrait<T> {
+    /// Get the expected results of the given chances of cooperation (do not record them).
+    fn expect_off_record(&self, probabilities: (f64, f64)) -> (T, T);
+
+    /// Play the chances and get the expected outputs (mutating scoreboard and recording them).
+    fn play_expected(&mut self, probabilities: (f64, f64)) -> (T, T) {
+        let last_rewards = self.expect_off_record(probabilities);
+        self.record_scores(last_rewards.clone());
+        last_rewards
+    }
 }
 
 /// A match for two players (consecutive plays on a machine).
 pub trait MatchTrait<T> {
+    /// What each round results in (e.g. [`crate::matches::RoundOutcome`]).
+    type Outcome;
+
     /// Play the next round and save it in the machine.
-    fn play(&mut self);
+    fn play(&mut self) -> Self::Outcome;
+
+    /// Get how many rounds are recorded so far (if counted).
+    fn round_count(&self) -> usize {
+        0
+    }
+
+    /// Get the scores of the players (by their index in the match) so far.
+    fn standings(&self) -> Vec<(usize, T)> {
+        vec![]
+    }
+
+    /// Restore the match to its state before playing any rounds.
+    fn reset(&mut self) {}
+
+    /// Get the outcome of the rounds played so far (`None` if not played or not known).
+    fn result(&self) -> Option<MatchResult<T>>
+    where
+        T: Ord + Clone,
+    {
+        None
+    }
 
     /// Play the number of rounds in succession.
-    fn play_for_rounds(&mut self, rounds: usize) {
-        for _ in 0..rounds {
+    fn play_for_rounds(&mut self, rounds: usize) -> Vec<Self::Outcome> {
+        (0..rounds).map(|_| self.play()).collect()
+    }
+
+    /// Play rounds until the predicate is satisfied or the maximum rounds are played.
+    ///
+    /// The predicate gets the rounds played so far by this call and the match itself (e.g. to
+    /// check the scores of the machine) before each round. Returns the number of rounds played.
+    fn play_until<F: FnMut(usize, &Self) -> bool>(
+        &mut self,
+        max_rounds: usize,
+        mut stop: F,
+    ) -> usize
+    where
+        Self: Sized,
+    {
+        let mut rounds = 0;
+        while rounds < max_rounds && !stop(rounds, self) {
             self.play();
+            rounds += 1;
         }
+        rounds
     }
 }
+
+/// Gets notified of what happens in matches and arenas (e.g. for visualization).
+///
+/// All the methods do nothing by default.
+#[auto_impl(&mut, Box)]
+pub trait MatchObserver<T> {
+    /// Called after each round is played.
+    #[allow(unused_variables)]
+    fn on_round(&mut self, outcome: &RoundOutcome<T>) {}
+
+    /// Called before the rounds of a pairing in an arena (with the types of the players).
+    #[allow(unused_variables)]
+    fn on_pairing_start(&mut self, p1_type: usize, p2_type: usize) {}
+
+    /// Called after the rounds of a pairing in an arena with its scores.
+    #[allow(unused_variables)]
+    fn on_pairing_end(&mut self, scores: &(T, T)) {}
+
+    /// Called after a generation of an arena is played with its population and scores.
+    #[allow(unused_variables)]
+    fn on_generation_end(&mut self, population: &[usize], scores: &[T]) {}
+}
@@ -0,0 +1,213 @@
+//! Plain text views of the simulations for interactive sessions.
+
+use crate::matches::GenerationSnapshot;
+
+/// Get the name of every type up to the last present one (at least the named ones).
+///
+/// Types without a name are named by their index.
+fn type_names_up_to<'a>(
+    present: impl IntoIterator<Item = &'a usize>,
+    type_names: &[&str],
+) -> Vec<String> {
+    let types = present
+        .into_iter()
+        .map(|&t| t + 1)
+        .max()
+        .unwrap_or_default()
+        .max(type_names.len());
+    (0..types)
+        .map(|t| match type_names.get(t) {
+            Some(name) => name.to_string(),
+            None => t.to_string(),
+        })
+        .collect()
+}
+
+/// Get a bar chart of the population with one row per type (in order).
+///
+/// Each row holds the name of the type, a bar of `#` as long as its count relative to the most
+/// frequent type (which gets `width` characters) and the count. Types without a name are named
+/// by their index and absent types get an empty bar.
+pub fn population_bar_chart(population: &[usize], type_names: &[&str], width: usize) -> String {
+    let names = type_names_up_to(population, type_names);
+    let mut counts = vec![0usize; names.len()];
+    for &player_type in population {
+        counts[player_type] += 1;
+    }
+    let name_width = names
+        .iter()
+        .map(|n| n.chars().count())
+        .max()
+        .unwrap_or_default();
+    let most = counts.iter().copied().max().unwrap_or_default();
+
+    let mut chart = String::new();
+    for (name, count) in names.iter().zip(counts) {
+        // rounded to the nearest character
+        let bar = match most {
+            0 => 0,
+            most => (count * width + most / 2) / most,
+        };
+        chart.push_str(&format!(
+            "{:<name_width$} |{:<width$}| {}\n",
+            name,
+            "#".repeat(bar),
+            count,
+            name_width = name_width,
+            width = width,
+        ));
+    }
+    chart
+}
+
+/// Get a table of the count of each type (rows) in the played population of each generation
+/// (columns).
+///
+/// The header holds the generation indices and the footer the total population. The columns of
+/// the generations are right aligned to the same width. Types without a name are named by their
+/// index and absent types count 0 (see [`crate::matches::Arena::run_for_generations`]).
+pub fn generation_table<T>(history: &[GenerationSnapshot<T>], type_names: &[&str]) -> String {
+    let names = type_names_up_to(history.iter().flat_map(|s| &s.players), type_names);
+    // the count of each type (row) in each generation (column)
+    let mut rows = vec![vec![0usize; history.len()]; names.len()];
+    for (column, snapshot) in history.iter().enumerate() {
+        for &player_type in &snapshot.players {
+            rows[player_type][column] += 1;
+        }
+    }
+    let header: Vec<String> = history
+        .iter()
+        .map(|s| s.generation_index.to_string())
+        .collect();
+    let totals: Vec<String> = history
+        .iter()
+        .map(|s| s.players.len().to_string())
+        .collect();
+
+    let (type_label, total_label) = ("Type", "Total");
+    let name_width = names
+        .iter()
+        .map(|n| n.chars().count())
+        .chain([type_label.len(), total_label.len()])
+        .max()
+        .unwrap_or_default();
+    let width = header
+        .iter()
+        .chain(totals.iter())
+        .map(String::len)
+        .max()
+        .unwrap_or_default();
+
+    let mut table = String::new();
+    let mut push_row = |label: &str, cells: &[String]| {
+        let mut row = format!("{:<name_width$}", label, name_width = name_width);
+        for cell in cells {
+            row.push_str(&format!(" {:>width$}", cell, width = width));
+        }
+        // no padding after the labels of an empty history
+        table.push_str(row.trim_end());
+        table.push('\n');
+    };
+    push_row(type_label, &header);
+    for (name, row) in names.iter().zip(rows) {
+        let cells: Vec<String> = row.iter().map(ToString::to_string).collect();
+        push_row(name, &cells);
+    }
+    push_row(total_label, &totals);
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        genetics::GeneticStrategy,
+        machines::Machine,
+        matches::Arena,
+        players::{AllCheat, AllCooperate, CopyCat},
+    };
+
+    #[test]
+    fn test_population_bar_chart() {
+        let chart = population_bar_chart(&[0, 1, 0, 0, 2, 0], &["CopyCat", "AllCheat"], 8);
+        assert_eq!(
+            chart.lines().collect::<Vec<_>>(),
+            [
+                "CopyCat  |########| 4",
+                "AllCheat |##      | 1",
+                "2        |##      | 1",
+            ]
+        );
+
+        // absent types are still listed
+        let chart = population_bar_chart(&[1, 1], &["CopyCat", "AllCheat", "Grudger"], 4);
+        assert_eq!(
+            chart.lines().collect::<Vec<_>>(),
+            [
+                "CopyCat  |    | 0",
+                "AllCheat |####| 2",
+                "Grudger  |    | 0"
+            ]
+        );
+        assert_eq!(
+            population_bar_chart(&[], &["CopyCat"], 3),
+            "CopyCat |   | 0\n"
+        );
+        assert_eq!(population_bar_chart(&[], &[], 3), "");
+    }
+
+    #[test]
+    fn test_generation_table() {
+        let snapshot = |generation_index, players: Vec<usize>| GenerationSnapshot::<isize> {
+            generation_index,
+            scores: vec![0; players.len()],
+            population: players.clone(),
+            players,
+        };
+        let history = [
+            snapshot(0, vec![0, 0, 1, 1, 1]),
+            snapshot(1, vec![0, 0, 0, 0, 1]),
+        ];
+        assert_eq!(
+            generation_table(&history, &["CopyCat", "AllCheat"])
+                .lines()
+                .collect::<Vec<_>>(),
+            [
+                "Type     0 1",
+                "CopyCat  2 4",
+                "AllCheat 3 1",
+                "Total    5 5",
+            ]
+        );
+        assert_eq!(
+            generation_table::<isize>(&[], &["CopyCat"]),
+            "Type\nCopyCat\nTotal\n"
+        );
+    }
+
+    #[test]
+    fn test_generation_table_of_arena() {
+        let mut arena = <Arena>::builder()
+            .machine(Machine::default())
+            .add_type(Box::new(CopyCat::default()), 4)
+            .add_type(Box::new(AllCheat), 5)
+            .add_type(Box::new(AllCooperate), 1)
+            .rounds_per_match(10)
+            .strategy(GeneticStrategy::CullingElitism(2, 2))
+            .build()
+            .unwrap();
+        let history = arena.run_for_generations(3);
+        assert_eq!(
+            generation_table(&history, &["CopyCat", "AllCheat"])
+                .lines()
+                .collect::<Vec<_>>(),
+            [
+                "Type      0  1  2",
+                "CopyCat   4  6  8",
+                "AllCheat  5  4  2",
+                "2         1  0  0",
+                "Total    10 10 10",
+            ]
+        );
+    }
+}
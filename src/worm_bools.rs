@@ -4,6 +4,7 @@ use std::ops::{Deref, Not};
 
 /// Boolean which can only be set to true.
 #[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct RiseOnlyBool(bool);
 
 impl RiseOnlyBool {
@@ -0,0 +1,69 @@
+//! A rough smoke benchmark of a parallel arena generation against playing the same pairings one
+//! by one (requires "parallel" feature).
+#![cfg(feature = "parallel")]
+
+use std::time::{Duration, Instant};
+
+use trust::{
+    genetics::GeneticStrategy,
+    machines::Machine,
+    matches::{MatchBuilder, SendArena},
+    players::*,
+    prelude::*,
+    traits::SyncPlayerTrait,
+};
+
+/// Get a new player of the given type.
+fn build(player_type: usize) -> Box<dyn SyncPlayerTrait<isize>> {
+    match player_type {
+        0 => Box::new(CopyCat::default()),
+        1 => Box::new(AllCheat),
+        2 => Box::new(AllCooperate),
+        3 => Box::new(Grudger::default()),
+        4 => Box::new(Detective::default()),
+        5 => Box::new(KindCopyCat::default()),
+        _ => Box::new(Simpleton::default()),
+    }
+}
+
+#[test]
+fn parallel_generation_does_not_regress() {
+    let (rounds, population): (usize, Vec<usize>) = (10, (0..300).map(|i| i % 7).collect());
+    let mut arena = SendArena::from_prototypes(
+        Machine::default(),
+        (0..7).map(build).collect(),
+        population.clone(),
+        rounds,
+        GeneticStrategy::Keep,
+    )
+    .unwrap();
+
+    let start = Instant::now();
+    arena.play_parallel();
+    let parallel = start.elapsed();
+
+    let start = Instant::now();
+    let mut expected = vec![0; population.len()];
+    for i in 0..population.len() {
+        for j in (i + 1)..population.len() {
+            let game = MatchBuilder::new()
+                .machine(Machine::default())
+                .players(build(population[i]), build(population[j]))
+                .rounds(rounds)
+                .build();
+            let standings = game.standings();
+            expected[i] += standings[0].1;
+            expected[j] += standings[1].1;
+        }
+    }
+    let sequential = start.elapsed();
+
+    assert_eq!(arena.scores(), expected);
+    // generous so a loaded (or single core) machine does not fail it
+    assert!(
+        parallel <= sequential * 3 + Duration::from_millis(200),
+        "parallel took {:?} while sequential took {:?}",
+        parallel,
+        sequential
+    );
+}